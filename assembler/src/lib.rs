@@ -3,4 +3,7 @@
 #[allow(dead_code)]
 mod v_encoder;
 #[allow(unused_imports)]
-pub use v_encoder::{Imm, Ivi, Ivv, Ivx, Uimm, VConfig, VInst, VReg, Vlmul, Vtypei, XReg};
+pub use v_encoder::{
+    Imm, Ivi, Ivv, Ivx, Mmm, Uimm, VConfig, VInst, VReg, Vlmul, Vtypei, VtypeiError, XReg, ELEN,
+    VILL,
+};
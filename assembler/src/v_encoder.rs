@@ -41,6 +41,19 @@ pub enum Vlmul {
 }
 
 impl Vlmul {
+    /// LMUL as a `(numerator, denominator)` pair, e.g. `(1, 4)` for `mf4`.
+    pub fn ratio(&self) -> (u32, u32) {
+        match self {
+            Vlmul::Mf8 => (1, 8),
+            Vlmul::Mf4 => (1, 4),
+            Vlmul::Mf2 => (1, 2),
+            Vlmul::M1 => (1, 1),
+            Vlmul::M2 => (2, 1),
+            Vlmul::M4 => (4, 1),
+            Vlmul::M8 => (8, 1),
+        }
+    }
+
     pub fn from_u8(value: u8) -> Vlmul {
         match value {
             0b101 => Vlmul::Mf8,
@@ -70,8 +83,43 @@ impl fmt::Display for Vlmul {
     }
 }
 
+/// The maximum element width (in bits) supported by the vector unit.
+pub const ELEN: u16 = 1024;
+
+/// The `vill` bit of the `vtype` CSR (XLEN = 64).
+pub const VILL: u64 = 1 << 63;
+
+/// Error returned when a SEW/LMUL setting can't be encoded by `Vtypei`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum VtypeiError {
+    /// SEW is not one of 8, 16, 32, ..., 1024
+    InvalidSew(u16),
+    /// SEW is wider than LMUL * ELEN
+    UnsupportedLmul { sew: u16, lmul: Vlmul },
+}
+
+impl fmt::Display for VtypeiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VtypeiError::InvalidSew(sew) => write!(f, "Invalid sew value for vtypei: {}", sew),
+            VtypeiError::UnsupportedLmul { sew, lmul } => write!(
+                f,
+                "Unsupported lmul value for vtypei: e{}, {} (ELEN = {})",
+                sew, lmul, ELEN
+            ),
+        }
+    }
+}
+
 impl Vtypei {
+    /// # Panics
+    ///
+    /// Panics if the SEW/LMUL pair is rejected by `Vtypei::try_new`.
     pub fn new(sew: u16, lmul: Vlmul, ta: bool, ma: bool) -> Vtypei {
+        Self::try_new(sew, lmul, ta, ma).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub fn try_new(sew: u16, lmul: Vlmul, ta: bool, ma: bool) -> Result<Vtypei, VtypeiError> {
         let vsew: u8 = match sew {
             8 => 0,
             16 => 1,
@@ -81,8 +129,14 @@ impl Vtypei {
             256 => 5,
             512 => 6,
             1024 => 7,
-            _ => panic!("Invalid sew value for vtypei: {}", sew),
+            _ => return Err(VtypeiError::InvalidSew(sew)),
         };
+        // Fractional LMUL must still hold at least one element of ELEN bits:
+        //   SEW <= LMUL * ELEN
+        let (num, den) = lmul.ratio();
+        if sew as u32 * den > ELEN as u32 * num {
+            return Err(VtypeiError::UnsupportedLmul { sew, lmul });
+        }
         let mut value = lmul as u8;
         value |= vsew << 3;
         if ta {
@@ -91,7 +145,7 @@ impl Vtypei {
         if ma {
             value |= 1 << 7;
         }
-        Vtypei(value)
+        Ok(Vtypei(value))
     }
 
     pub fn sew(&self) -> u16 {
@@ -117,6 +171,23 @@ impl Vtypei {
     pub fn ma(&self) -> bool {
         (self.0 & (1 << 7)) != 0
     }
+
+    /// VLMAX = LMUL * VLEN / SEW, the number of elements a register group can
+    /// hold. Returns 0 when this setting can't be supported with `vlen`.
+    pub fn vlmax(&self, vlen: u32) -> u32 {
+        let (num, den) = self.lmul().ratio();
+        vlen * num / (self.sew() as u32 * den)
+    }
+
+    /// The value `vset{i}vl{i}` writes to the `vtype` CSR, with only `vill`
+    /// set when this setting can't be supported with `vlen`.
+    pub fn vtype(&self, vlen: u32) -> u64 {
+        if self.vlmax(vlen) == 0 {
+            VILL
+        } else {
+            self.0 as u64
+        }
+    }
 }
 
 impl fmt::Display for Vtypei {
@@ -388,6 +459,20 @@ pub struct Ivi {
     pub vm: bool,
 }
 
+/// Vector Mask Instructions data structures
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Mmm {
+    pub vd: VReg,
+    pub vs2: VReg,
+    pub vs1: VReg,
+}
+
+impl fmt::Display for Mmm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}, {}", self.vd, self.vs2, self.vs1)
+    }
+}
+
 impl fmt::Display for Ivv {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut output = format!("{}, {}, {}", self.vd, self.vs2, self.vs1);
@@ -454,6 +539,18 @@ impl Ivx {
         )
     }
 }
+impl Mmm {
+    fn encode_u32(&self, funct6: u8) -> u32 {
+        encode_vai(
+            self.vd as u8,
+            funct3::OPMVV,
+            self.vs1 as u8,
+            self.vs2 as u8,
+            false,
+            funct6,
+        )
+    }
+}
 impl Ivi {
     fn encode_u32(&self, funct6: u8) -> u32 {
         encode_vai(
@@ -546,14 +643,48 @@ pub enum VInst {
     /// vdivu.vx vd, vs2, rs1, vm   # vector-scalar
     VdivuVx(Ivx),
 
+    // # Signed divide
+    /// vdiv.vv vd, vs2, vs1, vm   # Vector-vector
+    VdivVv(Ivv),
+    /// vdiv.vx vd, vs2, rs1, vm   # vector-scalar
+    VdivVx(Ivx),
+
     // # Unsigned remainder
     /// vremu.vv vd, vs2, vs1, vm   # Vector-vector
     VremuVv(Ivv),
     /// vremu.vx vd, vs2, rs1, vm   # vector-scalar
     VremuVx(Ivx),
+
     // # Signed remainder
-    /// TODO: vrem.vv vd, vs2, vs1, vm   # Vector-vector
-    /// TODO: vrem.vx vd, vs2, rs1, vm   # vector-scalar
+    /// vrem.vv vd, vs2, vs1, vm   # Vector-vector
+    VremVv(Ivv),
+    /// vrem.vx vd, vs2, rs1, vm   # vector-scalar
+    VremVx(Ivx),
+
+    // # Integer Min/Max
+    // # Unsigned minimum
+    /// vminu.vv vd, vs2, vs1, vm   # Vector-vector
+    VminuVv(Ivv),
+    /// vminu.vx vd, vs2, rs1, vm   # vector-scalar
+    VminuVx(Ivx),
+
+    // # Signed minimum
+    /// vmin.vv vd, vs2, vs1, vm   # Vector-vector
+    VminVv(Ivv),
+    /// vmin.vx vd, vs2, rs1, vm   # vector-scalar
+    VminVx(Ivx),
+
+    // # Unsigned maximum
+    /// vmaxu.vv vd, vs2, vs1, vm   # Vector-vector
+    VmaxuVv(Ivv),
+    /// vmaxu.vx vd, vs2, rs1, vm   # vector-scalar
+    VmaxuVx(Ivx),
+
+    // # Signed maximum
+    /// vmax.vv vd, vs2, vs1, vm   # Vector-vector
+    VmaxVv(Ivv),
+    /// vmax.vx vd, vs2, rs1, vm   # vector-scalar
+    VmaxVx(Ivx),
 
     // # Saturating adds of unsigned integers.
     /// vsaddu.vv vd, vs2, vs1, vm   # Vector-vector
@@ -585,6 +716,13 @@ pub enum VInst {
     /// vsrl.vi vd, vs2, uimm, vm   # vector-immediate
     VsrlVi(Ivi),
 
+    /// vsra.vv vd, vs2, vs1, vm   # Vector-vector
+    VsraVv(Ivv),
+    /// vsra.vx vd, vs2, rs1, vm   # vector-scalar
+    VsraVx(Ivx),
+    /// vsra.vi vd, vs2, uimm, vm   # vector-immediate
+    VsraVi(Ivi),
+
     // ==== Vector Bitwise Logical Instructions ====
     /// vand.vv vd, vs2, vs1, vm   # Vector-vector
     VandVv(Ivv),
@@ -630,6 +768,12 @@ pub enum VInst {
     /// vmsltu.vx vd, vs2, rs1, vm  # Vector-scalar
     VmsltuVx(Ivx),
 
+    // `<` # Set if less than, signed
+    /// vmslt.vv vd, vs2, vs1, vm  # Vector-vector
+    VmsltVv(Ivv),
+    /// vmslt.vx vd, vs2, rs1, vm  # vector-scalar
+    VmsltVx(Ivx),
+
     // `<=` # Set if less than or equal, unsigned
     /// vmsleu.vv vd, vs2, vs1, vm   # Vector-vector
     VmsleuVv(Ivv),
@@ -638,6 +782,14 @@ pub enum VInst {
     /// vmsleu.vi vd, vs2, imm, vm   # Vector-immediate
     VmsleuVi(Ivi),
 
+    // `<=` # Set if less than or equal, signed
+    /// vmsle.vv vd, vs2, vs1, vm   # Vector-vector
+    VmsleVv(Ivv),
+    /// vmsle.vx vd, vs2, rs1, vm   # vector-scalar
+    VmsleVx(Ivx),
+    /// vmsle.vi vd, vs2, imm, vm   # vector-immediate
+    VmsleVi(Ivi),
+
     // `>` # Set if greater than, unsigned
     /// vmsgtu.vv vd, vs2, vs1, vm   # Vector-vector
     VmsgtuVv(Ivv),
@@ -652,6 +804,173 @@ pub enum VInst {
     // va >= vb        vmsle{u}.vv vd, vb, va, vm    vmsge{u}.vv vd, va, vb, vm
     VmsgeuVv(Ivv),
 
+    // `>` # Set if greater than, signed
+    // vmsgt.vv is not provided directly, see vmsgtu.vv
+    VmsgtVv(Ivv),
+    /// vmsgt.vx vd, vs2, rs1, vm    # Vector-scalar
+    VmsgtVx(Ivx),
+    /// vmsgt.vi vd, vs2, imm, vm    # Vector-immediate
+    VmsgtVi(Ivi),
+
+    // `>=` # Set if greater than or equal, signed
+    // va >= vb        vmsle.vv vd, vb, va, vm    vmsge.vv vd, va, vb, vm
+    VmsgeVv(Ivv),
+
+    // ==== Vector Reduction Operations ====
+    // # Single-Width Integer Reduction Instructions
+    /// vredsum.vs  vd, vs2, vs1, vm   # vd[0] =  sum( vs1[0] , vs2[*] )
+    VredsumVs(Ivv),
+    /// vredmaxu.vs vd, vs2, vs1, vm   # vd[0] = maxu( vs1[0] , vs2[*] )
+    VredmaxuVs(Ivv),
+    /// vredminu.vs vd, vs2, vs1, vm   # vd[0] = minu( vs1[0] , vs2[*] )
+    VredminuVs(Ivv),
+    /// vredand.vs  vd, vs2, vs1, vm   # vd[0] =  and( vs1[0] , vs2[*] )
+    VredandVs(Ivv),
+    /// vredor.vs   vd, vs2, vs1, vm   # vd[0] =   or( vs1[0] , vs2[*] )
+    VredorVs(Ivv),
+    /// vredxor.vs  vd, vs2, vs1, vm   # vd[0] =  xor( vs1[0] , vs2[*] )
+    VredxorVs(Ivv),
+
+    // # Widening Integer Reduction Instructions
+    /// vwredsumu.vs vd, vs2, vs1, vm   # 2*SEW = 2*SEW + sum(zero-extend(SEW))
+    VwredsumuVs(Ivv),
+
+    // ==== Vector Integer Merge and Move Instructions ====
+    /// vmerge.vvm vd, vs2, vs1, v0  # vd[i] = v0.mask[i] ? vs1[i] : vs2[i]
+    VmergeVvm {
+        vd: VReg,
+        vs2: VReg,
+        vs1: VReg,
+    },
+    /// vmerge.vxm vd, vs2, rs1, v0  # vd[i] = v0.mask[i] ? x[rs1] : vs2[i]
+    VmergeVxm {
+        vd: VReg,
+        vs2: VReg,
+        rs1: XReg,
+    },
+    /// vmerge.vim vd, vs2, imm, v0  # vd[i] = v0.mask[i] ? imm : vs2[i]
+    VmergeVim {
+        vd: VReg,
+        vs2: VReg,
+        imm: Imm,
+    },
+
+    /// vmv.v.v vd, vs1  # vd[i] = vs1[i]
+    VmvVv {
+        vd: VReg,
+        vs1: VReg,
+    },
+    /// vmv.v.x vd, rs1  # vd[i] = x[rs1]
+    VmvVx {
+        vd: VReg,
+        rs1: XReg,
+    },
+    /// vmv.v.i vd, imm  # vd[i] = imm
+    VmvVi {
+        vd: VReg,
+        imm: Imm,
+    },
+
+    // ==== Vector Permutation Instructions ====
+    // # Integer Scalar Move Instructions
+    /// vmv.x.s rd, vs2  # x[rd] = vs2[0] (vs1=0)
+    VmvXs {
+        rd: XReg,
+        vs2: VReg,
+    },
+    /// vmv.s.x vd, rs1  # vd[0] = x[rs1] (vs2=0)
+    VmvSx {
+        vd: VReg,
+        rs1: XReg,
+    },
+
+    // # Vector Slide Instructions
+    /// vslideup.vx vd, vs2, rs1, vm        # vd[i+x[rs1]] = vs2[i]
+    VslideupVx(Ivx),
+    /// vslideup.vi vd, vs2, uimm, vm       # vd[i+uimm] = vs2[i]
+    VslideupVi(Ivi),
+    /// vslidedown.vx vd, vs2, rs1, vm      # vd[i] = vs2[i+x[rs1]]
+    VslidedownVx(Ivx),
+    /// vslidedown.vi vd, vs2, uimm, vm     # vd[i] = vs2[i+uimm]
+    VslidedownVi(Ivi),
+    /// vslide1up.vx vd, vs2, rs1, vm       # vd[0]=x[rs1], vd[i+1] = vs2[i]
+    Vslide1upVx(Ivx),
+    /// vslide1down.vx vd, vs2, rs1, vm     # vd[i] = vs2[i+1], vd[vl-1]=x[rs1]
+    Vslide1downVx(Ivx),
+
+    // # Vector Register Gather Instructions
+    /// vrgather.vv vd, vs2, vs1, vm        # vd[i] = (vs1[i] >= VLMAX) ? 0 : vs2[vs1[i]];
+    VrgatherVv(Ivv),
+    /// vrgatherei16.vv vd, vs2, vs1, vm    # vd[i] = (vs1[i] >= VLMAX) ? 0 : vs2[vs1[i]];
+    Vrgatherei16Vv(Ivv),
+    /// vrgather.vx vd, vs2, rs1, vm        # vd[i] = (x[rs1] >= VLMAX) ? 0 : vs2[x[rs1]]
+    VrgatherVx(Ivx),
+    /// vrgather.vi vd, vs2, uimm, vm       # vd[i] = (uimm >= VLMAX) ? 0 : vs2[uimm]
+    VrgatherVi(Ivi),
+
+    // # Vector Compress Instruction
+    /// vcompress.vm vd, vs2, vs1  # Compress into vd elements of vs2 where vs1 is enabled
+    VcompressVm {
+        vd: VReg,
+        vs2: VReg,
+        vs1: VReg,
+    },
+
+    // ==== Vector Mask Instructions ====
+    // # Vector Mask-Register Logical Instructions
+    /// vmand.mm vd, vs2, vs1     # vd.mask[i] =   vs2.mask[i] &&  vs1.mask[i]
+    VmandMm(Mmm),
+    /// vmnand.mm vd, vs2, vs1    # vd.mask[i] = !(vs2.mask[i] &&  vs1.mask[i])
+    VmnandMm(Mmm),
+    /// vmandn.mm vd, vs2, vs1    # vd.mask[i] =   vs2.mask[i] && !vs1.mask[i]
+    VmandnMm(Mmm),
+    /// vmxor.mm vd, vs2, vs1     # vd.mask[i] =   vs2.mask[i] ^^  vs1.mask[i]
+    VmxorMm(Mmm),
+    /// vmor.mm vd, vs2, vs1      # vd.mask[i] =   vs2.mask[i] ||  vs1.mask[i]
+    VmorMm(Mmm),
+    /// vmnor.mm vd, vs2, vs1     # vd.mask[i] = !(vs2.mask[i] ||  vs1.mask[i])
+    VmnorMm(Mmm),
+    /// vmorn.mm vd, vs2, vs1     # vd.mask[i] =   vs2.mask[i] || !vs1.mask[i]
+    VmornMm(Mmm),
+    /// vmxnor.mm vd, vs2, vs1    # vd.mask[i] = !(vs2.mask[i] ^^  vs1.mask[i])
+    VmxnorMm(Mmm),
+
+    /// vcpop.m rd, vs2, vm  # x[rd] = sum_i ( vs2.mask[i] && v0.mask[i] )
+    VcpopM {
+        rd: XReg,
+        vs2: VReg,
+        vm: bool,
+    },
+    /// vmsbf.m vd, vs2, vm  # set-before-first mask bit
+    VmsbfM {
+        vd: VReg,
+        vs2: VReg,
+        vm: bool,
+    },
+    /// vmsif.m vd, vs2, vm  # set-including-first mask bit
+    VmsifM {
+        vd: VReg,
+        vs2: VReg,
+        vm: bool,
+    },
+    /// vmsof.m vd, vs2, vm  # set-only-first mask bit
+    VmsofM {
+        vd: VReg,
+        vs2: VReg,
+        vm: bool,
+    },
+    /// viota.m vd, vs2, vm  # vd[i] = sum_{j<i} vs2.mask[j]
+    ViotaM {
+        vd: VReg,
+        vs2: VReg,
+        vm: bool,
+    },
+    /// vid.v vd, vm  # Write element ID to destination.
+    VidV {
+        vd: VReg,
+        vm: bool,
+    },
+
     /// vfirst.m rd, vs2, vm
     VfirstM {
         rd: XReg,
@@ -709,6 +1028,18 @@ mod funct3 {
     pub(crate) const OPCFG: u8 = 0b111;
 }
 
+mod vs1_unary {
+    // See: https://github.com/riscv/riscv-v-spec/blob/master/inst-table.adoc
+    // VWXUNARY0
+    pub(crate) const VCPOP: u8 = 0b10000;
+    // VMUNARY0
+    pub(crate) const VMSBF: u8 = 0b00001;
+    pub(crate) const VMSOF: u8 = 0b00010;
+    pub(crate) const VMSIF: u8 = 0b00011;
+    pub(crate) const VIOTA: u8 = 0b10000;
+    pub(crate) const VID: u8 = 0b10001;
+}
+
 mod funct6 {
     // See: https://github.com/riscv/riscv-v-spec/blob/master/inst-table.adoc
     pub(crate) const VADD: u8 = 0b000000;
@@ -723,14 +1054,49 @@ mod funct6 {
     pub(crate) const VSSUBU: u8 = 0b100010;
     pub(crate) const VSLL: u8 = 0b100101;
     pub(crate) const VSRL: u8 = 0b101000;
+    pub(crate) const VSRA: u8 = 0b101001;
+    pub(crate) const VMINU: u8 = 0b000100;
+    pub(crate) const VMIN: u8 = 0b000101;
+    pub(crate) const VMAXU: u8 = 0b000110;
+    pub(crate) const VMAX: u8 = 0b000111;
     pub(crate) const VAND: u8 = 0b001001;
     pub(crate) const VOR: u8 = 0b001010;
     pub(crate) const VXOR: u8 = 0b001011;
     pub(crate) const VMSEQ: u8 = 0b011000;
     pub(crate) const VMSNE: u8 = 0b011001;
     pub(crate) const VMSLTU: u8 = 0b011010;
+    pub(crate) const VMSLT: u8 = 0b011011;
     pub(crate) const VMSLEU: u8 = 0b011100;
+    pub(crate) const VMSLE: u8 = 0b011101;
     pub(crate) const VMSGTU: u8 = 0b011110;
+    pub(crate) const VMSGT: u8 = 0b011111;
+    pub(crate) const VMAND: u8 = 0b011001;
+    pub(crate) const VMNAND: u8 = 0b011101;
+    pub(crate) const VMANDN: u8 = 0b011000;
+    pub(crate) const VMXOR: u8 = 0b011011;
+    pub(crate) const VMOR: u8 = 0b011010;
+    pub(crate) const VMNOR: u8 = 0b011110;
+    pub(crate) const VMORN: u8 = 0b011100;
+    pub(crate) const VMXNOR: u8 = 0b011111;
+    pub(crate) const VMUNARY0: u8 = 0b010100;
+    pub(crate) const VREDSUM: u8 = 0b000000;
+    pub(crate) const VREDAND: u8 = 0b000001;
+    pub(crate) const VREDOR: u8 = 0b000010;
+    pub(crate) const VREDXOR: u8 = 0b000011;
+    pub(crate) const VREDMINU: u8 = 0b000100;
+    pub(crate) const VREDMAXU: u8 = 0b000110;
+    pub(crate) const VWREDSUMU: u8 = 0b110000;
+    pub(crate) const VMERGE: u8 = 0b010111;
+    pub(crate) const VMV: u8 = 0b010111;
+    pub(crate) const VWXUNARY0: u8 = 0b010000;
+    pub(crate) const VRXUNARY0: u8 = 0b010000;
+    pub(crate) const VSLIDEUP: u8 = 0b001110;
+    pub(crate) const VSLIDEDOWN: u8 = 0b001111;
+    pub(crate) const VSLIDE1UP: u8 = 0b001110;
+    pub(crate) const VSLIDE1DOWN: u8 = 0b001111;
+    pub(crate) const VRGATHER: u8 = 0b001100;
+    pub(crate) const VRGATHEREI16: u8 = 0b001110;
+    pub(crate) const VCOMPRESS: u8 = 0b010111;
 }
 
 impl VInst {
@@ -770,12 +1136,48 @@ impl VInst {
             VInst::VdivuVx(ivx) => {
                 return ivx.encode_u32(funct6::VDIVU, funct3::OPMVX);
             }
+            VInst::VdivVv(ivv) => {
+                return ivv.encode_u32(funct6::VDIV, funct3::OPMVV);
+            }
+            VInst::VdivVx(ivx) => {
+                return ivx.encode_u32(funct6::VDIV, funct3::OPMVX);
+            }
             VInst::VremuVv(ivv) => {
                 return ivv.encode_u32(funct6::VREMU, funct3::OPMVV);
             }
             VInst::VremuVx(ivx) => {
                 return ivx.encode_u32(funct6::VREMU, funct3::OPMVX);
             }
+            VInst::VremVv(ivv) => {
+                return ivv.encode_u32(funct6::VREM, funct3::OPMVV);
+            }
+            VInst::VremVx(ivx) => {
+                return ivx.encode_u32(funct6::VREM, funct3::OPMVX);
+            }
+            VInst::VminuVv(ivv) => {
+                return ivv.encode_u32(funct6::VMINU, funct3::OPIVV);
+            }
+            VInst::VminuVx(ivx) => {
+                return ivx.encode_u32(funct6::VMINU, funct3::OPIVX);
+            }
+            VInst::VminVv(ivv) => {
+                return ivv.encode_u32(funct6::VMIN, funct3::OPIVV);
+            }
+            VInst::VminVx(ivx) => {
+                return ivx.encode_u32(funct6::VMIN, funct3::OPIVX);
+            }
+            VInst::VmaxuVv(ivv) => {
+                return ivv.encode_u32(funct6::VMAXU, funct3::OPIVV);
+            }
+            VInst::VmaxuVx(ivx) => {
+                return ivx.encode_u32(funct6::VMAXU, funct3::OPIVX);
+            }
+            VInst::VmaxVv(ivv) => {
+                return ivv.encode_u32(funct6::VMAX, funct3::OPIVV);
+            }
+            VInst::VmaxVx(ivx) => {
+                return ivx.encode_u32(funct6::VMAX, funct3::OPIVX);
+            }
             VInst::VsadduVv(ivv) => {
                 return ivv.encode_u32(funct6::VSADDU, funct3::OPIVV);
             }
@@ -811,6 +1213,15 @@ impl VInst {
             VInst::VsrlVi(ivi) => {
                 return ivi.encode_u32(funct6::VSRL);
             }
+            VInst::VsraVv(ivv) => {
+                return ivv.encode_u32(funct6::VSRA, funct3::OPIVV);
+            }
+            VInst::VsraVx(ivx) => {
+                return ivx.encode_u32(funct6::VSRA, funct3::OPIVX);
+            }
+            VInst::VsraVi(ivi) => {
+                return ivi.encode_u32(funct6::VSRA);
+            }
 
             // ==== Vector Bitwise Logical Instructions ====
             VInst::VandVv(ivv) => {
@@ -866,6 +1277,12 @@ impl VInst {
             VInst::VmsltuVx(ivx) => {
                 return ivx.encode_u32(funct6::VMSLTU, funct3::OPIVX);
             }
+            VInst::VmsltVv(ivv) => {
+                return ivv.encode_u32(funct6::VMSLT, funct3::OPIVV);
+            }
+            VInst::VmsltVx(ivx) => {
+                return ivx.encode_u32(funct6::VMSLT, funct3::OPIVX);
+            }
             VInst::VmsleuVv(ivv) => {
                 return ivv.encode_u32(funct6::VMSLEU, funct3::OPIVV);
             }
@@ -875,6 +1292,15 @@ impl VInst {
             VInst::VmsleuVi(ivi) => {
                 return ivi.encode_u32(funct6::VMSLEU);
             }
+            VInst::VmsleVv(ivv) => {
+                return ivv.encode_u32(funct6::VMSLE, funct3::OPIVV);
+            }
+            VInst::VmsleVx(ivx) => {
+                return ivx.encode_u32(funct6::VMSLE, funct3::OPIVX);
+            }
+            VInst::VmsleVi(ivi) => {
+                return ivi.encode_u32(funct6::VMSLE);
+            }
             VInst::VmsgtuVv(Ivv { vd, vs2, vs1, vm }) => {
                 return VInst::VmsltuVv(Ivv {
                     vd,
@@ -899,6 +1325,242 @@ impl VInst {
                 })
                 .encode_u32();
             }
+            VInst::VmsgtVv(Ivv { vd, vs2, vs1, vm }) => {
+                return VInst::VmsltVv(Ivv {
+                    vd,
+                    vm,
+                    vs2: vs1,
+                    vs1: vs2,
+                })
+                .encode_u32();
+            }
+            VInst::VmsgtVx(ivx) => {
+                return ivx.encode_u32(funct6::VMSGT, funct3::OPIVX);
+            }
+            VInst::VmsgtVi(ivi) => {
+                return ivi.encode_u32(funct6::VMSGT);
+            }
+            VInst::VmsgeVv(Ivv { vd, vs2, vs1, vm }) => {
+                return VInst::VmsleVv(Ivv {
+                    vd,
+                    vm,
+                    vs2: vs1,
+                    vs1: vs2,
+                })
+                .encode_u32();
+            }
+
+            // ==== Vector Reduction Operations ====
+            VInst::VredsumVs(ivv) => {
+                return ivv.encode_u32(funct6::VREDSUM, funct3::OPMVV);
+            }
+            VInst::VredmaxuVs(ivv) => {
+                return ivv.encode_u32(funct6::VREDMAXU, funct3::OPMVV);
+            }
+            VInst::VredminuVs(ivv) => {
+                return ivv.encode_u32(funct6::VREDMINU, funct3::OPMVV);
+            }
+            VInst::VredandVs(ivv) => {
+                return ivv.encode_u32(funct6::VREDAND, funct3::OPMVV);
+            }
+            VInst::VredorVs(ivv) => {
+                return ivv.encode_u32(funct6::VREDOR, funct3::OPMVV);
+            }
+            VInst::VredxorVs(ivv) => {
+                return ivv.encode_u32(funct6::VREDXOR, funct3::OPMVV);
+            }
+            VInst::VwredsumuVs(ivv) => {
+                return ivv.encode_u32(funct6::VWREDSUMU, funct3::OPIVV);
+            }
+
+            // ==== Vector Integer Merge and Move Instructions ====
+            VInst::VmergeVvm { vd, vs2, vs1 } => {
+                return encode_vai(
+                    vd as u8,
+                    funct3::OPIVV,
+                    vs1 as u8,
+                    vs2 as u8,
+                    true,
+                    funct6::VMERGE,
+                );
+            }
+            VInst::VmergeVxm { vd, vs2, rs1 } => {
+                return encode_vai(
+                    vd as u8,
+                    funct3::OPIVX,
+                    rs1 as u8,
+                    vs2 as u8,
+                    true,
+                    funct6::VMERGE,
+                );
+            }
+            VInst::VmergeVim { vd, vs2, imm } => {
+                return encode_vai(
+                    vd as u8,
+                    funct3::OPIVI,
+                    imm.0,
+                    vs2 as u8,
+                    true,
+                    funct6::VMERGE,
+                );
+            }
+            VInst::VmvVv { vd, vs1 } => {
+                return encode_vai(vd as u8, funct3::OPIVV, vs1 as u8, 0, false, funct6::VMV);
+            }
+            VInst::VmvVx { vd, rs1 } => {
+                return encode_vai(vd as u8, funct3::OPIVX, rs1 as u8, 0, false, funct6::VMV);
+            }
+            VInst::VmvVi { vd, imm } => {
+                return encode_vai(vd as u8, funct3::OPIVI, imm.0, 0, false, funct6::VMV);
+            }
+
+            // ==== Vector Permutation Instructions ====
+            VInst::VmvXs { rd, vs2 } => {
+                return encode_vai(
+                    rd as u8,
+                    funct3::OPMVV,
+                    0,
+                    vs2 as u8,
+                    false,
+                    funct6::VWXUNARY0,
+                );
+            }
+            VInst::VmvSx { vd, rs1 } => {
+                return encode_vai(
+                    vd as u8,
+                    funct3::OPMVX,
+                    rs1 as u8,
+                    0,
+                    false,
+                    funct6::VRXUNARY0,
+                );
+            }
+            VInst::VslideupVx(ivx) => {
+                return ivx.encode_u32(funct6::VSLIDEUP, funct3::OPIVX);
+            }
+            VInst::VslideupVi(ivi) => {
+                return ivi.encode_u32(funct6::VSLIDEUP);
+            }
+            VInst::VslidedownVx(ivx) => {
+                return ivx.encode_u32(funct6::VSLIDEDOWN, funct3::OPIVX);
+            }
+            VInst::VslidedownVi(ivi) => {
+                return ivi.encode_u32(funct6::VSLIDEDOWN);
+            }
+            VInst::Vslide1upVx(ivx) => {
+                return ivx.encode_u32(funct6::VSLIDE1UP, funct3::OPMVX);
+            }
+            VInst::Vslide1downVx(ivx) => {
+                return ivx.encode_u32(funct6::VSLIDE1DOWN, funct3::OPMVX);
+            }
+            VInst::VrgatherVv(ivv) => {
+                return ivv.encode_u32(funct6::VRGATHER, funct3::OPIVV);
+            }
+            VInst::Vrgatherei16Vv(ivv) => {
+                return ivv.encode_u32(funct6::VRGATHEREI16, funct3::OPIVV);
+            }
+            VInst::VrgatherVx(ivx) => {
+                return ivx.encode_u32(funct6::VRGATHER, funct3::OPIVX);
+            }
+            VInst::VrgatherVi(ivi) => {
+                return ivi.encode_u32(funct6::VRGATHER);
+            }
+            VInst::VcompressVm { vd, vs2, vs1 } => {
+                return encode_vai(
+                    vd as u8,
+                    funct3::OPMVV,
+                    vs1 as u8,
+                    vs2 as u8,
+                    false,
+                    funct6::VCOMPRESS,
+                );
+            }
+
+            // ==== Vector Mask Instructions ====
+            VInst::VmandMm(mmm) => {
+                return mmm.encode_u32(funct6::VMAND);
+            }
+            VInst::VmnandMm(mmm) => {
+                return mmm.encode_u32(funct6::VMNAND);
+            }
+            VInst::VmandnMm(mmm) => {
+                return mmm.encode_u32(funct6::VMANDN);
+            }
+            VInst::VmxorMm(mmm) => {
+                return mmm.encode_u32(funct6::VMXOR);
+            }
+            VInst::VmorMm(mmm) => {
+                return mmm.encode_u32(funct6::VMOR);
+            }
+            VInst::VmnorMm(mmm) => {
+                return mmm.encode_u32(funct6::VMNOR);
+            }
+            VInst::VmornMm(mmm) => {
+                return mmm.encode_u32(funct6::VMORN);
+            }
+            VInst::VmxnorMm(mmm) => {
+                return mmm.encode_u32(funct6::VMXNOR);
+            }
+            VInst::VcpopM { rd, vs2, vm } => {
+                return encode_vai(
+                    rd as u8,
+                    funct3::OPMVV,
+                    vs1_unary::VCPOP,
+                    vs2 as u8,
+                    vm,
+                    funct6::VWXUNARY0,
+                );
+            }
+            VInst::VmsbfM { vd, vs2, vm } => {
+                return encode_vai(
+                    vd as u8,
+                    funct3::OPMVV,
+                    vs1_unary::VMSBF,
+                    vs2 as u8,
+                    vm,
+                    funct6::VMUNARY0,
+                );
+            }
+            VInst::VmsifM { vd, vs2, vm } => {
+                return encode_vai(
+                    vd as u8,
+                    funct3::OPMVV,
+                    vs1_unary::VMSIF,
+                    vs2 as u8,
+                    vm,
+                    funct6::VMUNARY0,
+                );
+            }
+            VInst::VmsofM { vd, vs2, vm } => {
+                return encode_vai(
+                    vd as u8,
+                    funct3::OPMVV,
+                    vs1_unary::VMSOF,
+                    vs2 as u8,
+                    vm,
+                    funct6::VMUNARY0,
+                );
+            }
+            VInst::ViotaM { vd, vs2, vm } => {
+                return encode_vai(
+                    vd as u8,
+                    funct3::OPMVV,
+                    vs1_unary::VIOTA,
+                    vs2 as u8,
+                    vm,
+                    funct6::VMUNARY0,
+                );
+            }
+            VInst::VidV { vd, vm } => {
+                return encode_vai(
+                    vd as u8,
+                    funct3::OPMVV,
+                    vs1_unary::VID,
+                    0,
+                    vm,
+                    funct6::VMUNARY0,
+                );
+            }
 
             // ==== other instructions ====
             VInst::VfirstM { rd, vs2, vm } => {
@@ -972,6 +1634,20 @@ impl VInst {
     pub fn encode_bytes(self) -> [u8; 4] {
         self.encode_u32().to_le_bytes()
     }
+
+    /// Whether this instruction folds all active elements of `vs2` into `vd[0]`.
+    pub fn is_reduction(&self) -> bool {
+        matches!(
+            self,
+            VInst::VredsumVs(_)
+                | VInst::VredmaxuVs(_)
+                | VInst::VredminuVs(_)
+                | VInst::VredandVs(_)
+                | VInst::VredorVs(_)
+                | VInst::VredxorVs(_)
+                | VInst::VwredsumuVs(_)
+        )
+    }
 }
 
 impl fmt::Display for VInst {
@@ -1011,12 +1687,48 @@ impl fmt::Display for VInst {
             VInst::VdivuVx(ivx) => {
                 write!(f, "vdivu.vx {}", ivx)
             }
+            VInst::VdivVv(ivv) => {
+                write!(f, "vdiv.vv {}", ivv)
+            }
+            VInst::VdivVx(ivx) => {
+                write!(f, "vdiv.vx {}", ivx)
+            }
             VInst::VremuVv(ivv) => {
                 write!(f, "vremu.vv {}", ivv)
             }
             VInst::VremuVx(ivx) => {
                 write!(f, "vremu.vx {}", ivx)
             }
+            VInst::VremVv(ivv) => {
+                write!(f, "vrem.vv {}", ivv)
+            }
+            VInst::VremVx(ivx) => {
+                write!(f, "vrem.vx {}", ivx)
+            }
+            VInst::VminuVv(ivv) => {
+                write!(f, "vminu.vv {}", ivv)
+            }
+            VInst::VminuVx(ivx) => {
+                write!(f, "vminu.vx {}", ivx)
+            }
+            VInst::VminVv(ivv) => {
+                write!(f, "vmin.vv {}", ivv)
+            }
+            VInst::VminVx(ivx) => {
+                write!(f, "vmin.vx {}", ivx)
+            }
+            VInst::VmaxuVv(ivv) => {
+                write!(f, "vmaxu.vv {}", ivv)
+            }
+            VInst::VmaxuVx(ivx) => {
+                write!(f, "vmaxu.vx {}", ivx)
+            }
+            VInst::VmaxVv(ivv) => {
+                write!(f, "vmax.vv {}", ivv)
+            }
+            VInst::VmaxVx(ivx) => {
+                write!(f, "vmax.vx {}", ivx)
+            }
             VInst::VsadduVv(ivv) => {
                 write!(f, "vsaddu.vv {}", ivv)
             }
@@ -1052,6 +1764,15 @@ impl fmt::Display for VInst {
             VInst::VsrlVi(ivi) => {
                 write!(f, "vsrl.vi {}", ivi)
             }
+            VInst::VsraVv(ivv) => {
+                write!(f, "vsra.vv {}", ivv)
+            }
+            VInst::VsraVx(ivx) => {
+                write!(f, "vsra.vx {}", ivx)
+            }
+            VInst::VsraVi(ivi) => {
+                write!(f, "vsra.vi {}", ivi)
+            }
 
             // ==== Vector Bitwise Logical Instructions ====
             VInst::VandVv(ivv) => {
@@ -1107,6 +1828,12 @@ impl fmt::Display for VInst {
             VInst::VmsltuVx(ivx) => {
                 write!(f, "vmsltu.vx {}", ivx)
             }
+            VInst::VmsltVv(ivv) => {
+                write!(f, "vmslt.vv {}", ivv)
+            }
+            VInst::VmsltVx(ivx) => {
+                write!(f, "vmslt.vx {}", ivx)
+            }
             VInst::VmsleuVv(ivv) => {
                 write!(f, "vmsleu.vv {}", ivv)
             }
@@ -1116,6 +1843,15 @@ impl fmt::Display for VInst {
             VInst::VmsleuVi(ivi) => {
                 write!(f, "vmsleu.vi {}", ivi)
             }
+            VInst::VmsleVv(ivv) => {
+                write!(f, "vmsle.vv {}", ivv)
+            }
+            VInst::VmsleVx(ivx) => {
+                write!(f, "vmsle.vx {}", ivx)
+            }
+            VInst::VmsleVi(ivi) => {
+                write!(f, "vmsle.vi {}", ivi)
+            }
             VInst::VmsgtuVv(Ivv { vd, vs2, vs1, vm }) => VInst::VmsltuVv(Ivv {
                 vd: *vd,
                 vm: *vm,
@@ -1136,6 +1872,179 @@ impl fmt::Display for VInst {
                 vs1: *vs2,
             })
             .fmt(f),
+            VInst::VmsgtVv(Ivv { vd, vs2, vs1, vm }) => VInst::VmsltVv(Ivv {
+                vd: *vd,
+                vm: *vm,
+                vs2: *vs1,
+                vs1: *vs2,
+            })
+            .fmt(f),
+            VInst::VmsgtVx(ivx) => {
+                write!(f, "vmsgt.vx {}", ivx)
+            }
+            VInst::VmsgtVi(ivi) => {
+                write!(f, "vmsgt.vi {}", ivi)
+            }
+            VInst::VmsgeVv(Ivv { vd, vs2, vs1, vm }) => VInst::VmsleVv(Ivv {
+                vd: *vd,
+                vm: *vm,
+                vs2: *vs1,
+                vs1: *vs2,
+            })
+            .fmt(f),
+
+            // ==== Vector Reduction Operations ====
+            VInst::VredsumVs(ivv) => {
+                write!(f, "vredsum.vs {}", ivv)
+            }
+            VInst::VredmaxuVs(ivv) => {
+                write!(f, "vredmaxu.vs {}", ivv)
+            }
+            VInst::VredminuVs(ivv) => {
+                write!(f, "vredminu.vs {}", ivv)
+            }
+            VInst::VredandVs(ivv) => {
+                write!(f, "vredand.vs {}", ivv)
+            }
+            VInst::VredorVs(ivv) => {
+                write!(f, "vredor.vs {}", ivv)
+            }
+            VInst::VredxorVs(ivv) => {
+                write!(f, "vredxor.vs {}", ivv)
+            }
+            VInst::VwredsumuVs(ivv) => {
+                write!(f, "vwredsumu.vs {}", ivv)
+            }
+
+            // ==== Vector Integer Merge and Move Instructions ====
+            VInst::VmergeVvm { vd, vs2, vs1 } => {
+                write!(f, "vmerge.vvm {}, {}, {}, v0", vd, vs2, vs1)
+            }
+            VInst::VmergeVxm { vd, vs2, rs1 } => {
+                write!(f, "vmerge.vxm {}, {}, {}, v0", vd, vs2, rs1)
+            }
+            VInst::VmergeVim { vd, vs2, imm } => {
+                write!(f, "vmerge.vim {}, {}, {}, v0", vd, vs2, imm)
+            }
+            VInst::VmvVv { vd, vs1 } => {
+                write!(f, "vmv.v.v {}, {}", vd, vs1)
+            }
+            VInst::VmvVx { vd, rs1 } => {
+                write!(f, "vmv.v.x {}, {}", vd, rs1)
+            }
+            VInst::VmvVi { vd, imm } => {
+                write!(f, "vmv.v.i {}, {}", vd, imm)
+            }
+
+            // ==== Vector Permutation Instructions ====
+            VInst::VmvXs { rd, vs2 } => {
+                write!(f, "vmv.x.s {}, {}", rd, vs2)
+            }
+            VInst::VmvSx { vd, rs1 } => {
+                write!(f, "vmv.s.x {}, {}", vd, rs1)
+            }
+            VInst::VslideupVx(ivx) => {
+                write!(f, "vslideup.vx {}", ivx)
+            }
+            VInst::VslideupVi(ivi) => {
+                write!(f, "vslideup.vi {}", ivi)
+            }
+            VInst::VslidedownVx(ivx) => {
+                write!(f, "vslidedown.vx {}", ivx)
+            }
+            VInst::VslidedownVi(ivi) => {
+                write!(f, "vslidedown.vi {}", ivi)
+            }
+            VInst::Vslide1upVx(ivx) => {
+                write!(f, "vslide1up.vx {}", ivx)
+            }
+            VInst::Vslide1downVx(ivx) => {
+                write!(f, "vslide1down.vx {}", ivx)
+            }
+            VInst::VrgatherVv(ivv) => {
+                write!(f, "vrgather.vv {}", ivv)
+            }
+            VInst::Vrgatherei16Vv(ivv) => {
+                write!(f, "vrgatherei16.vv {}", ivv)
+            }
+            VInst::VrgatherVx(ivx) => {
+                write!(f, "vrgather.vx {}", ivx)
+            }
+            VInst::VrgatherVi(ivi) => {
+                write!(f, "vrgather.vi {}", ivi)
+            }
+            VInst::VcompressVm { vd, vs2, vs1 } => {
+                write!(f, "vcompress.vm {}, {}, {}", vd, vs2, vs1)
+            }
+
+            // ==== Vector Mask Instructions ====
+            VInst::VmandMm(mmm) => {
+                write!(f, "vmand.mm {}", mmm)
+            }
+            VInst::VmnandMm(mmm) => {
+                write!(f, "vmnand.mm {}", mmm)
+            }
+            VInst::VmandnMm(mmm) => {
+                write!(f, "vmandn.mm {}", mmm)
+            }
+            VInst::VmxorMm(mmm) => {
+                write!(f, "vmxor.mm {}", mmm)
+            }
+            VInst::VmorMm(mmm) => {
+                write!(f, "vmor.mm {}", mmm)
+            }
+            VInst::VmnorMm(mmm) => {
+                write!(f, "vmnor.mm {}", mmm)
+            }
+            VInst::VmornMm(mmm) => {
+                write!(f, "vmorn.mm {}", mmm)
+            }
+            VInst::VmxnorMm(mmm) => {
+                write!(f, "vmxnor.mm {}", mmm)
+            }
+            VInst::VcpopM { rd, vs2, vm } => {
+                let mut output = format!("{}, {}", rd, vs2);
+                if *vm {
+                    output = format!("{}, vm", output);
+                }
+                write!(f, "vcpop.m {}", output)
+            }
+            VInst::VmsbfM { vd, vs2, vm } => {
+                let mut output = format!("{}, {}", vd, vs2);
+                if *vm {
+                    output = format!("{}, vm", output);
+                }
+                write!(f, "vmsbf.m {}", output)
+            }
+            VInst::VmsifM { vd, vs2, vm } => {
+                let mut output = format!("{}, {}", vd, vs2);
+                if *vm {
+                    output = format!("{}, vm", output);
+                }
+                write!(f, "vmsif.m {}", output)
+            }
+            VInst::VmsofM { vd, vs2, vm } => {
+                let mut output = format!("{}, {}", vd, vs2);
+                if *vm {
+                    output = format!("{}, vm", output);
+                }
+                write!(f, "vmsof.m {}", output)
+            }
+            VInst::ViotaM { vd, vs2, vm } => {
+                let mut output = format!("{}, {}", vd, vs2);
+                if *vm {
+                    output = format!("{}, vm", output);
+                }
+                write!(f, "viota.m {}", output)
+            }
+            VInst::VidV { vd, vm } => {
+                let mut output = format!("{}", vd);
+                if *vm {
+                    output = format!("{}, vm", output);
+                }
+                write!(f, "vid.v {}", output)
+            }
+
             VInst::VfirstM { rd, vs2, vm } => {
                 let mut output = format!("{}, {}", rd, vs2);
                 if *vm {
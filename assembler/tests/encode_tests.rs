@@ -0,0 +1,471 @@
+use rvv_assembler::VReg::*;
+use rvv_assembler::{Imm, Ivi, Ivv, Ivx, Mmm, VInst, VReg, XReg};
+
+// Expected words come from `llvm-mc -triple=riscv64 -mattr=+v -show-encoding`
+// on the displayed text, with `, vm` written as binutils' `, v0.t`.
+
+fn vv(vd: VReg, vs2: VReg, vs1: VReg, vm: bool) -> Ivv {
+    Ivv { vd, vs2, vs1, vm }
+}
+
+fn vx(vd: VReg, vs2: VReg, rs1: XReg, vm: bool) -> Ivx {
+    Ivx { vd, vs2, rs1, vm }
+}
+
+fn vi(vd: VReg, vs2: VReg, imm: u8, vm: bool) -> Ivi {
+    Ivi {
+        vd,
+        vs2,
+        imm: Imm(imm),
+        vm,
+    }
+}
+
+fn check(cases: &[(VInst, &str, u32)]) {
+    for &(inst, text, word) in cases {
+        assert_eq!(inst.to_string(), text);
+        assert_eq!(
+            inst.encode_u32(),
+            word,
+            "{}: {:#010x} != {:#010x}",
+            text,
+            inst.encode_u32(),
+            word
+        );
+        assert_eq!(inst.encode_bytes(), word.to_le_bytes());
+    }
+}
+
+#[test]
+fn encode_merge_move_slide_gather() {
+    check(&[
+        (
+            VInst::VmergeVvm {
+                vd: V1,
+                vs2: V2,
+                vs1: V3,
+            },
+            "vmerge.vvm v1, v2, v3, v0",
+            0x5c21_80d7,
+        ),
+        (
+            VInst::VmergeVxm {
+                vd: V1,
+                vs2: V2,
+                rs1: XReg::T0,
+            },
+            "vmerge.vxm v1, v2, t0, v0",
+            0x5c22_c0d7,
+        ),
+        (
+            VInst::VmergeVim {
+                vd: V1,
+                vs2: V2,
+                imm: Imm(7),
+            },
+            "vmerge.vim v1, v2, 7, v0",
+            0x5c23_b0d7,
+        ),
+        (
+            VInst::VmvVv { vd: V4, vs1: V12 },
+            "vmv.v.v v4, v12",
+            0x5e06_0257,
+        ),
+        (
+            VInst::VmvVx {
+                vd: V4,
+                rs1: XReg::A0,
+            },
+            "vmv.v.x v4, a0",
+            0x5e05_4257,
+        ),
+        (
+            VInst::VmvVi {
+                vd: V4,
+                imm: Imm(9),
+            },
+            "vmv.v.i v4, 9",
+            0x5e04_b257,
+        ),
+        (
+            VInst::VmvXs {
+                rd: XReg::A1,
+                vs2: V8,
+            },
+            "vmv.x.s a1, v8",
+            0x4280_25d7,
+        ),
+        (
+            VInst::VmvSx {
+                vd: V8,
+                rs1: XReg::A1,
+            },
+            "vmv.s.x v8, a1",
+            0x4205_e457,
+        ),
+        (
+            VInst::VslideupVx(vx(V4, V8, XReg::A0, false)),
+            "vslideup.vx v4, v8, a0",
+            0x3a85_4257,
+        ),
+        (
+            VInst::VslideupVi(vi(V16, V24, 5, false)),
+            "vslideup.vi v16, v24, 5",
+            0x3b82_b857,
+        ),
+        (
+            VInst::VslidedownVx(vx(V4, V8, XReg::A0, true)),
+            "vslidedown.vx v4, v8, a0, vm",
+            0x3c85_4257,
+        ),
+        (
+            VInst::VslidedownVi(vi(V16, V24, 5, false)),
+            "vslidedown.vi v16, v24, 5",
+            0x3f82_b857,
+        ),
+        (
+            VInst::Vslide1upVx(vx(V4, V8, XReg::A0, false)),
+            "vslide1up.vx v4, v8, a0",
+            0x3a85_6257,
+        ),
+        (
+            VInst::Vslide1downVx(vx(V4, V8, XReg::A0, false)),
+            "vslide1down.vx v4, v8, a0",
+            0x3e85_6257,
+        ),
+        (
+            VInst::VrgatherVv(vv(V1, V2, V3, false)),
+            "vrgather.vv v1, v2, v3",
+            0x3221_80d7,
+        ),
+        (
+            VInst::Vrgatherei16Vv(vv(V1, V2, V3, true)),
+            "vrgatherei16.vv v1, v2, v3, vm",
+            0x3821_80d7,
+        ),
+        (
+            VInst::VrgatherVx(vx(V4, V8, XReg::A0, false)),
+            "vrgather.vx v4, v8, a0",
+            0x3285_4257,
+        ),
+        (
+            VInst::VrgatherVi(vi(V16, V24, 31, false)),
+            "vrgather.vi v16, v24, 31",
+            0x338f_b857,
+        ),
+        (
+            VInst::VcompressVm {
+                vd: V1,
+                vs2: V2,
+                vs1: V3,
+            },
+            "vcompress.vm v1, v2, v3",
+            0x5e21_a0d7,
+        ),
+    ]);
+}
+
+#[test]
+fn encode_reductions() {
+    check(&[
+        (
+            VInst::VredsumVs(vv(V1, V2, V3, false)),
+            "vredsum.vs v1, v2, v3",
+            0x0221_a0d7,
+        ),
+        (
+            VInst::VredmaxuVs(vv(V1, V2, V3, false)),
+            "vredmaxu.vs v1, v2, v3",
+            0x1a21_a0d7,
+        ),
+        (
+            VInst::VredminuVs(vv(V1, V2, V3, false)),
+            "vredminu.vs v1, v2, v3",
+            0x1221_a0d7,
+        ),
+        (
+            VInst::VredandVs(vv(V1, V2, V3, true)),
+            "vredand.vs v1, v2, v3, vm",
+            0x0421_a0d7,
+        ),
+        (
+            VInst::VredorVs(vv(V1, V2, V3, false)),
+            "vredor.vs v1, v2, v3",
+            0x0a21_a0d7,
+        ),
+        (
+            VInst::VredxorVs(vv(V1, V2, V3, false)),
+            "vredxor.vs v1, v2, v3",
+            0x0e21_a0d7,
+        ),
+        (
+            VInst::VwredsumuVs(vv(V1, V2, V3, false)),
+            "vwredsumu.vs v1, v2, v3",
+            0xc221_80d7,
+        ),
+    ]);
+}
+
+#[test]
+fn encode_mask_ops() {
+    check(&[
+        (
+            VInst::VmandMm(Mmm {
+                vd: V1,
+                vs2: V2,
+                vs1: V3,
+            }),
+            "vmand.mm v1, v2, v3",
+            0x6621_a0d7,
+        ),
+        (
+            VInst::VmnandMm(Mmm {
+                vd: V1,
+                vs2: V2,
+                vs1: V3,
+            }),
+            "vmnand.mm v1, v2, v3",
+            0x7621_a0d7,
+        ),
+        (
+            VInst::VmandnMm(Mmm {
+                vd: V1,
+                vs2: V2,
+                vs1: V3,
+            }),
+            "vmandn.mm v1, v2, v3",
+            0x6221_a0d7,
+        ),
+        (
+            VInst::VmxorMm(Mmm {
+                vd: V1,
+                vs2: V2,
+                vs1: V3,
+            }),
+            "vmxor.mm v1, v2, v3",
+            0x6e21_a0d7,
+        ),
+        (
+            VInst::VmorMm(Mmm {
+                vd: V1,
+                vs2: V2,
+                vs1: V3,
+            }),
+            "vmor.mm v1, v2, v3",
+            0x6a21_a0d7,
+        ),
+        (
+            VInst::VmnorMm(Mmm {
+                vd: V1,
+                vs2: V2,
+                vs1: V3,
+            }),
+            "vmnor.mm v1, v2, v3",
+            0x7a21_a0d7,
+        ),
+        (
+            VInst::VmornMm(Mmm {
+                vd: V1,
+                vs2: V2,
+                vs1: V3,
+            }),
+            "vmorn.mm v1, v2, v3",
+            0x7221_a0d7,
+        ),
+        (
+            VInst::VmxnorMm(Mmm {
+                vd: V1,
+                vs2: V2,
+                vs1: V3,
+            }),
+            "vmxnor.mm v1, v2, v3",
+            0x7e21_a0d7,
+        ),
+        (
+            VInst::VcpopM {
+                rd: XReg::A0,
+                vs2: V2,
+                vm: false,
+            },
+            "vcpop.m a0, v2",
+            0x4228_2557,
+        ),
+        (
+            VInst::VcpopM {
+                rd: XReg::A0,
+                vs2: V2,
+                vm: true,
+            },
+            "vcpop.m a0, v2, vm",
+            0x4028_2557,
+        ),
+        (
+            VInst::VmsbfM {
+                vd: V1,
+                vs2: V2,
+                vm: false,
+            },
+            "vmsbf.m v1, v2",
+            0x5220_a0d7,
+        ),
+        (
+            VInst::VmsifM {
+                vd: V1,
+                vs2: V2,
+                vm: true,
+            },
+            "vmsif.m v1, v2, vm",
+            0x5021_a0d7,
+        ),
+        (
+            VInst::VmsofM {
+                vd: V1,
+                vs2: V2,
+                vm: false,
+            },
+            "vmsof.m v1, v2",
+            0x5221_20d7,
+        ),
+        (
+            VInst::ViotaM {
+                vd: V1,
+                vs2: V2,
+                vm: false,
+            },
+            "viota.m v1, v2",
+            0x5228_20d7,
+        ),
+        (VInst::VidV { vd: V5, vm: false }, "vid.v v5", 0x5208_a2d7),
+        (
+            VInst::VidV { vd: V5, vm: true },
+            "vid.v v5, vm",
+            0x5008_a2d7,
+        ),
+    ]);
+}
+
+#[test]
+fn encode_min_max_compare_div() {
+    check(&[
+        (
+            VInst::VminuVv(vv(V1, V2, V3, false)),
+            "vminu.vv v1, v2, v3",
+            0x1221_80d7,
+        ),
+        (
+            VInst::VminuVx(vx(V4, V8, XReg::A0, false)),
+            "vminu.vx v4, v8, a0",
+            0x1285_4257,
+        ),
+        (
+            VInst::VminVv(vv(V1, V2, V3, false)),
+            "vmin.vv v1, v2, v3",
+            0x1621_80d7,
+        ),
+        (
+            VInst::VminVx(vx(V4, V8, XReg::A0, false)),
+            "vmin.vx v4, v8, a0",
+            0x1685_4257,
+        ),
+        (
+            VInst::VmaxuVv(vv(V1, V2, V3, false)),
+            "vmaxu.vv v1, v2, v3",
+            0x1a21_80d7,
+        ),
+        (
+            VInst::VmaxuVx(vx(V4, V8, XReg::A0, false)),
+            "vmaxu.vx v4, v8, a0",
+            0x1a85_4257,
+        ),
+        (
+            VInst::VmaxVv(vv(V1, V2, V3, true)),
+            "vmax.vv v1, v2, v3, vm",
+            0x1c21_80d7,
+        ),
+        (
+            VInst::VmaxVx(vx(V4, V8, XReg::A0, false)),
+            "vmax.vx v4, v8, a0",
+            0x1e85_4257,
+        ),
+        (
+            VInst::VmsltVv(vv(V1, V2, V3, false)),
+            "vmslt.vv v1, v2, v3",
+            0x6e21_80d7,
+        ),
+        (
+            VInst::VmsltVx(vx(V4, V8, XReg::A0, false)),
+            "vmslt.vx v4, v8, a0",
+            0x6e85_4257,
+        ),
+        (
+            VInst::VmsleVv(vv(V1, V2, V3, false)),
+            "vmsle.vv v1, v2, v3",
+            0x7621_80d7,
+        ),
+        (
+            VInst::VmsleVx(vx(V4, V8, XReg::A0, false)),
+            "vmsle.vx v4, v8, a0",
+            0x7685_4257,
+        ),
+        (
+            VInst::VmsleVi(vi(V16, V24, 5, false)),
+            "vmsle.vi v16, v24, 5",
+            0x7782_b857,
+        ),
+        (
+            VInst::VmsgtVv(vv(V1, V2, V3, false)),
+            "vmslt.vv v1, v3, v2",
+            0x6e31_00d7,
+        ),
+        (
+            VInst::VmsgtVx(vx(V4, V8, XReg::A0, true)),
+            "vmsgt.vx v4, v8, a0, vm",
+            0x7c85_4257,
+        ),
+        (
+            VInst::VmsgtVi(vi(V16, V24, 5, false)),
+            "vmsgt.vi v16, v24, 5",
+            0x7f82_b857,
+        ),
+        (
+            VInst::VmsgeVv(vv(V1, V2, V3, false)),
+            "vmsle.vv v1, v3, v2",
+            0x7631_00d7,
+        ),
+        (
+            VInst::VdivVv(vv(V1, V2, V3, false)),
+            "vdiv.vv v1, v2, v3",
+            0x8621_a0d7,
+        ),
+        (
+            VInst::VdivVx(vx(V4, V8, XReg::A0, false)),
+            "vdiv.vx v4, v8, a0",
+            0x8685_6257,
+        ),
+        (
+            VInst::VremVv(vv(V1, V2, V3, false)),
+            "vrem.vv v1, v2, v3",
+            0x8e21_a0d7,
+        ),
+        (
+            VInst::VremVx(vx(V4, V8, XReg::A0, false)),
+            "vrem.vx v4, v8, a0",
+            0x8e85_6257,
+        ),
+        (
+            VInst::VsraVv(vv(V1, V2, V3, false)),
+            "vsra.vv v1, v2, v3",
+            0xa621_80d7,
+        ),
+        (
+            VInst::VsraVx(vx(V4, V8, XReg::A0, false)),
+            "vsra.vx v4, v8, a0",
+            0xa685_4257,
+        ),
+        (
+            VInst::VsraVi(vi(V16, V24, 3, true)),
+            "vsra.vi v16, v24, 3, vm",
+            0xa581_b857,
+        ),
+    ]);
+}
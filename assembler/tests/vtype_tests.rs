@@ -0,0 +1,140 @@
+use rvv_assembler::{Uimm, VConfig, VInst, Vlmul, Vtypei, VtypeiError, XReg, ELEN, VILL};
+
+const LMULS: [Vlmul; 7] = [
+    Vlmul::Mf8,
+    Vlmul::Mf4,
+    Vlmul::Mf2,
+    Vlmul::M1,
+    Vlmul::M2,
+    Vlmul::M4,
+    Vlmul::M8,
+];
+
+#[test]
+fn vtypei_rejects_invalid_sew() {
+    // Wider than ELEN, for every LMUL
+    for &lmul in LMULS.iter() {
+        assert_eq!(
+            Vtypei::try_new(2 * ELEN, lmul, true, true),
+            Err(VtypeiError::InvalidSew(2 * ELEN))
+        );
+    }
+    for &sew in [0, 1, 4, 24, 96, 1023].iter() {
+        assert_eq!(
+            Vtypei::try_new(sew, Vlmul::M1, false, false),
+            Err(VtypeiError::InvalidSew(sew))
+        );
+    }
+}
+
+#[test]
+fn vtypei_fractional_lmul_large_sew() {
+    // SEW <= LMUL * ELEN
+    let cases = [
+        (Vlmul::Mf2, 512, 1024),
+        (Vlmul::Mf4, 256, 512),
+        (Vlmul::Mf8, 128, 256),
+    ];
+    for &(lmul, widest, rejected) in cases.iter() {
+        let vtypei = Vtypei::try_new(widest, lmul, true, true).unwrap();
+        assert_eq!((vtypei.sew(), vtypei.lmul()), (widest, lmul));
+        assert_eq!(
+            Vtypei::try_new(rejected, lmul, true, true),
+            Err(VtypeiError::UnsupportedLmul {
+                sew: rejected,
+                lmul
+            })
+        );
+    }
+    // Any integer LMUL holds an ELEN-wide element
+    for &lmul in LMULS[3..].iter() {
+        assert!(Vtypei::try_new(ELEN, lmul, false, false).is_ok());
+    }
+    assert_eq!(
+        VtypeiError::UnsupportedLmul {
+            sew: 1024,
+            lmul: Vlmul::Mf2
+        }
+        .to_string(),
+        "Unsupported lmul value for vtypei: e1024, mf2 (ELEN = 1024)"
+    );
+    assert_eq!(
+        VtypeiError::InvalidSew(24).to_string(),
+        "Invalid sew value for vtypei: 24"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unsupported lmul value for vtypei: e256, mf8")]
+fn vtypei_new_panics_on_unsupported_lmul() {
+    Vtypei::new(256, Vlmul::Mf8, true, true);
+}
+
+#[test]
+fn vtypei_vlmax_each_lmul() {
+    // VLEN = 256, SEW = 64
+    let expected = [0, 1, 2, 4, 8, 16, 32];
+    for (&lmul, &vlmax) in LMULS.iter().zip(expected.iter()) {
+        let vtypei = Vtypei::new(64, lmul, true, true);
+        assert_eq!(vtypei.vlmax(256), vlmax, "e64, {}", lmul);
+        // vlmul in bits 0..3, vsew in 3..6, vta at 6 and vma at 7
+        let bits = lmul as u64 | 3 << 3 | 1 << 6 | 1 << 7;
+        let vtype = if vlmax == 0 { VILL } else { bits };
+        assert_eq!(vtypei.vtype(256), vtype, "e64, {}", lmul);
+    }
+    assert_eq!(Vtypei::new(256, Vlmul::M1, true, true).vlmax(256), 1);
+    assert_eq!(Vtypei::new(1024, Vlmul::M8, true, true).vlmax(256), 2);
+    assert_eq!(Vtypei::new(1024, Vlmul::M1, true, true).vtype(256), VILL);
+    assert_eq!(Vtypei::new(8, Vlmul::Mf8, false, false).vlmax(2048), 32);
+}
+
+#[test]
+fn vtypei_vsetivli_encoding() {
+    // Expected words from llvm-mc, with the default `tu, mu` written out
+    let cases = [
+        (
+            XReg::Zero,
+            4,
+            Vtypei::new(64, Vlmul::M1, true, true),
+            "vsetivli zero, 4, e64, m1, ta, ma",
+            0xcd82_7057,
+        ),
+        (
+            XReg::T0,
+            31,
+            Vtypei::new(8, Vlmul::Mf8, false, false),
+            "vsetivli t0, 31, e8, mf8",
+            0xc05f_f2d7,
+        ),
+        (
+            XReg::A0,
+            1,
+            Vtypei::new(32, Vlmul::Mf2, true, false),
+            "vsetivli a0, 1, e32, mf2, ta",
+            0xc570_f557,
+        ),
+        (
+            XReg::Zero,
+            16,
+            Vtypei::new(16, Vlmul::M8, false, true),
+            "vsetivli zero, 16, e16, m8, ma",
+            0xc8b8_7057,
+        ),
+        (
+            XReg::Zero,
+            2,
+            Vtypei::new(64, Vlmul::Mf4, true, true),
+            "vsetivli zero, 2, e64, mf4, ta, ma",
+            0xcde1_7057,
+        ),
+    ];
+    for &(rd, avl, vtypei, text, word) in cases.iter() {
+        let inst = VInst::VConfig(VConfig::Vsetivli {
+            rd,
+            uimm: Uimm(avl),
+            vtypei,
+        });
+        assert_eq!(inst.to_string(), text);
+        assert_eq!(inst.encode_u32(), word, "{}", text);
+    }
+}
@@ -1,21 +1,18 @@
-use crate::rvv_impl::mul_reduce_internal;
-use core::cmp::Ordering;
-use crunchy::unroll;
-
-use byteorder::{BigEndian, ByteOrder};
+use rvv_simulator_runtime::{MontgomeryParams, Uint};
 
 /// 256-bit, stack allocated biginteger for use in prime field
 /// arithmetic.
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+///
+/// A thin wrapper over the runtime's `Uint<4>`, so the scalar fields, the
+/// `#[rvv_vector]` code and the `rvv_crypto` assembly share one
+/// representation: four little-endian `u64` words.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(C)]
-pub struct U256(pub [u128; 2]);
+pub struct U256(pub Uint<4>);
 
 impl From<[u64; 4]> for U256 {
     fn from(d: [u64; 4]) -> Self {
-        let mut a = [0u128; 2];
-        a[0] = (d[1] as u128) << 64 | d[0] as u128;
-        a[1] = (d[3] as u128) << 64 | d[2] as u128;
-        U256(a)
+        U256(Uint(d))
     }
 }
 
@@ -27,54 +24,22 @@ impl From<u64> for U256 {
 
 /// 512-bit, stack allocated biginteger for use in extension
 /// field serialization and scalar interpretation.
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(C)]
-pub struct U512(pub [u128; 4]);
+pub struct U512(pub Uint<8>);
 
 impl From<[u64; 8]> for U512 {
     fn from(d: [u64; 8]) -> Self {
-        let mut a = [0u128; 4];
-        a[0] = (d[1] as u128) << 64 | d[0] as u128;
-        a[1] = (d[3] as u128) << 64 | d[2] as u128;
-        a[2] = (d[5] as u128) << 64 | d[4] as u128;
-        a[3] = (d[7] as u128) << 64 | d[6] as u128;
-        U512(a)
+        U512(Uint(d))
     }
 }
 
 impl U512 {
     /// Multiplies c1 by modulo, adds c0.
     pub fn new(c1: &U256, c0: &U256, modulo: &U256) -> U512 {
-        let mut res = [0; 4];
-
-        debug_assert_eq!(c1.0.len(), 2);
-        unroll! {
-            for i in 0..2 {
-                mac_digit(i, &mut res, &modulo.0, c1.0[i]);
-            }
-        }
-
-        let mut carry = 0;
-
-        debug_assert_eq!(res.len(), 4);
-        unroll! {
-            for i in 0..2 {
-                res[i] = adc(res[i], c0.0[i], &mut carry);
-            }
-        }
-
-        unroll! {
-            for i in 0..2 {
-                let (a1, a0) = split_u128(res[i + 2]);
-                let (c, r0) = split_u128(a0 + carry);
-                let (c, r1) = split_u128(a1 + c);
-                carry = c;
-
-                res[i + 2] = combine_u128(r1, r0);
-            }
-        }
+        let (res, carry) = c1.0.full_mul(modulo.0).overflowing_add(c0.0.into());
 
-        debug_assert!(0 == carry);
+        debug_assert!(!carry);
 
         U512(res)
     }
@@ -87,110 +52,28 @@ impl U512 {
             });
         }
 
-        let mut n = [0; 4];
-        n[3] = BigEndian::read_u128(&s[0..]);
-        n[2] = BigEndian::read_u128(&s[16..]);
-        n[1] = BigEndian::read_u128(&s[32..]);
-        n[0] = BigEndian::read_u128(&s[48..]);
-        Ok(U512(n))
+        Ok(U512(Uint::from_big_endian(s)))
     }
 
     pub fn get_bit(&self, n: usize) -> Option<bool> {
         if n >= 512 {
             None
         } else {
-            let part = n / 128;
-            let bit = n - (128 * part);
-
-            Some(self.0[part] & (1 << bit) > 0)
+            Some(self.0.bit(n))
         }
     }
 
     /// Divides self by modulo, returning remainder and, if
     /// possible, a quotient smaller than the modulus.
     pub fn divrem(&self, modulo: &U256) -> (Option<U256>, U256) {
-        let mut q = Some(U256::zero());
-        let mut r = U256::zero();
-
-        for i in (0..512).rev() {
-            // NB: modulo's first two bits are always unset
-            // so this will never destroy information
-            mul2(&mut r.0);
-            assert!(r.set_bit(0, self.get_bit(i).unwrap()));
-            if &r >= modulo {
-                sub_noborrow(&mut r.0, &modulo.0);
-                if q.is_some() && !q.as_mut().unwrap().set_bit(i, true) {
-                    q = None
-                }
-            }
-        }
+        let (q, r) = self.0.div_mod(modulo.0.into());
+        let q = q.checked_resize::<4>().map(U256).filter(|q| q < modulo);
 
-        if q.is_some() && (q.as_ref().unwrap() >= modulo) {
-            (None, r)
-        } else {
-            (q, r)
-        }
+        (q, U256(r.into()))
     }
 
     pub fn interpret(buf: &[u8; 64]) -> U512 {
-        let mut n = [0; 4];
-        n[3] = BigEndian::read_u128(&buf[0..]);
-        n[2] = BigEndian::read_u128(&buf[16..]);
-        n[1] = BigEndian::read_u128(&buf[32..]);
-        n[0] = BigEndian::read_u128(&buf[48..]);
-        U512(n)
-    }
-}
-
-impl Ord for U512 {
-    #[inline]
-    fn cmp(&self, other: &U512) -> Ordering {
-        if self.0[3] < other.0[3] {
-            return Ordering::Less;
-        }
-        if self.0[3] > other.0[3] {
-            return Ordering::Greater;
-        }
-        if self.0[2] < other.0[2] {
-            return Ordering::Less;
-        }
-        if self.0[2] > other.0[2] {
-            return Ordering::Greater;
-        }
-        if self.0[1] < other.0[1] {
-            return Ordering::Less;
-        }
-        if self.0[1] > other.0[1] {
-            return Ordering::Greater;
-        }
-        self.0[0].cmp(&other.0[0])
-    }
-}
-
-impl PartialOrd for U512 {
-    #[inline]
-    fn partial_cmp(&self, other: &U512) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for U256 {
-    #[inline]
-    fn cmp(&self, other: &U256) -> Ordering {
-        if self.0[1] < other.0[1] {
-            return Ordering::Less;
-        }
-        if self.0[1] > other.0[1] {
-            return Ordering::Greater;
-        }
-        self.0[0].cmp(&other.0[0])
-    }
-}
-
-impl PartialOrd for U256 {
-    #[inline]
-    fn partial_cmp(&self, other: &U256) -> Option<Ordering> {
-        Some(self.cmp(other))
+        U512(Uint::from_big_endian(buf))
     }
 }
 
@@ -209,10 +92,7 @@ impl U256 {
                 actual: s.len(),
             });
         }
-        let mut n = [0; 2];
-        n[1] = BigEndian::read_u128(&s[0..]);
-        n[0] = BigEndian::read_u128(&s[16..]);
-        Ok(U256(n))
+        Ok(U256(Uint::from_big_endian(s)))
     }
 
     pub fn to_big_endian(&self, s: &mut [u8]) -> Result<(), Error> {
@@ -222,37 +102,29 @@ impl U256 {
                 actual: s.len(),
             });
         }
-        BigEndian::write_u128(&mut s[0..], self.0[1]);
-        BigEndian::write_u128(&mut s[16..], self.0[0]);
+        self.0.to_big_endian(s);
         Ok(())
     }
 
     #[inline]
     pub fn zero() -> U256 {
-        U256([0, 0])
+        U256(Uint::zero())
     }
 
     #[inline]
     pub fn one() -> U256 {
-        U256([1, 0])
+        U256(Uint::one())
     }
 
     pub fn is_zero(&self) -> bool {
-        self.0[0] == 0 && self.0[1] == 0
+        self.0.is_zero()
     }
 
     pub fn set_bit(&mut self, n: usize, to: bool) -> bool {
         if n >= 256 {
             false
         } else {
-            let part = n / 128;
-            let bit = n - (128 * part);
-
-            if to {
-                self.0[part] |= 1 << bit;
-            } else {
-                self.0[part] &= !(1 << bit);
-            }
+            self.0.set_bit(n, to);
 
             true
         }
@@ -262,65 +134,48 @@ impl U256 {
         if n >= 256 {
             None
         } else {
-            let part = n / 128;
-            let bit = n - (128 * part);
-
-            Some(self.0[part] & (1 << bit) > 0)
+            Some(self.0.bit(n))
         }
     }
 
+    // The moduli are below 2^254, so the sums below never carry out.
+
     /// Add `other` to `self` (mod `modulo`)
     pub fn add(&mut self, other: &U256, modulo: &U256) {
-        add_nocarry(&mut self.0, &other.0);
+        self.0 = self.0.wrapping_add(other.0);
 
         if *self >= *modulo {
-            sub_noborrow(&mut self.0, &modulo.0);
+            self.0 = self.0.wrapping_sub(modulo.0);
         }
     }
 
     /// Subtract `other` from `self` (mod `modulo`)
     pub fn sub(&mut self, other: &U256, modulo: &U256) {
         if *self < *other {
-            add_nocarry(&mut self.0, &modulo.0);
+            self.0 = self.0.wrapping_add(modulo.0);
         }
 
-        sub_noborrow(&mut self.0, &other.0);
+        self.0 = self.0.wrapping_sub(other.0);
     }
 
-    /// Multiply `self` by `other` (mod `modulo`) via the Montgomery
-    /// multiplication method.
-    pub fn mul(&mut self, other: &U256, modulo: &U256, inv: u128) {
-        // original one
-        if cfg!(any(feature = "use_rvv_vector", feature = "use_rvv_asm")) {
-            let inv_high = if inv == 0x9ede7d651eca6ac987d20782e4866389 {
-                0xf57a22b791888c6bd8afcbd01833da80u128
-            } else if inv == 0x6586864b4c6911b3c2e1f593efffffff {
-                0x73f82f1d0d8341b2e39a982899062391
-            } else {
-                0
-            };
-            mul_reduce_internal(&mut self.0, &other.0, &modulo.0, inv, inv_high);
-        } else {
-            mul_reduce(&mut self.0, &other.0, &modulo.0, inv);
-            if *self >= *modulo {
-                sub_noborrow(&mut self.0, &modulo.0);
-            }
-        }
+    /// Multiply `self` by `other` (mod `params.modulus()`) via the Montgomery
+    /// multiplication method. `other` must be below the modulus; `self`
+    /// doesn't need to be reduced.
+    #[inline]
+    pub fn mul(&mut self, other: &U256, params: &MontgomeryParams<4>) {
+        self.0 = params.mul(self.0, other.0);
     }
 
     /// Turn `self` into its additive inverse (mod `modulo`)
     pub fn neg(&mut self, modulo: &U256) {
-        if *self > Self::zero() {
-            let mut tmp = modulo.0;
-            sub_noborrow(&mut tmp, &self.0);
-
-            self.0 = tmp;
+        if !self.is_zero() {
+            self.0 = modulo.0.wrapping_sub(self.0);
         }
     }
 
     #[inline]
     pub fn is_even(&self) -> bool {
-        self.0[0] & 1 == 0
+        !self.0.bit(0)
     }
 
     /// Turn `self` into its multiplicative inverse (mod `modulo`)
@@ -336,31 +191,27 @@ impl U256 {
 
         while u != U256::one() && v != U256::one() {
             while u.is_even() {
-                div2(&mut u.0);
+                u.0 = u.0 >> 1;
 
-                if b.is_even() {
-                    div2(&mut b.0);
-                } else {
-                    add_nocarry(&mut b.0, &modulo.0);
-                    div2(&mut b.0);
+                if !b.is_even() {
+                    b.0 = b.0.wrapping_add(modulo.0);
                 }
+                b.0 = b.0 >> 1;
             }
             while v.is_even() {
-                div2(&mut v.0);
+                v.0 = v.0 >> 1;
 
-                if c.is_even() {
-                    div2(&mut c.0);
-                } else {
-                    add_nocarry(&mut c.0, &modulo.0);
-                    div2(&mut c.0);
+                if !c.is_even() {
+                    c.0 = c.0.wrapping_add(modulo.0);
                 }
+                c.0 = c.0 >> 1;
             }
 
             if u >= v {
-                sub_noborrow(&mut u.0, &v.0);
+                u.0 = u.0.wrapping_sub(v.0);
                 b.sub(&c, modulo);
             } else {
-                sub_noborrow(&mut v.0, &u.0);
+                v.0 = v.0.wrapping_sub(u.0);
                 c.sub(&b, modulo);
             }
         }
@@ -397,124 +248,3 @@ impl<'a> Iterator for BitIterator<'a> {
         }
     }
 }
-
-/// Divide by two
-#[inline]
-fn div2(a: &mut [u128; 2]) {
-    let tmp = a[1] << 127;
-    a[1] >>= 1;
-    a[0] >>= 1;
-    a[0] |= tmp;
-}
-
-/// Multiply by two
-#[inline]
-fn mul2(a: &mut [u128; 2]) {
-    let tmp = a[0] >> 127;
-    a[0] <<= 1;
-    a[1] <<= 1;
-    a[1] |= tmp;
-}
-
-#[inline(always)]
-fn split_u128(i: u128) -> (u128, u128) {
-    (i >> 64, i & 0xFFFFFFFFFFFFFFFF)
-}
-
-#[inline(always)]
-fn combine_u128(hi: u128, lo: u128) -> u128 {
-    (hi << 64) | lo
-}
-
-#[inline]
-fn adc(a: u128, b: u128, carry: &mut u128) -> u128 {
-    let (c, d) = a.overflowing_add(b);
-    let (e, f) = c.overflowing_add(*carry);
-    *carry = (d | f) as u128;
-    e
-}
-
-#[inline]
-fn add_nocarry(a: &mut [u128; 2], b: &[u128; 2]) {
-    let (c, d) = a[0].overflowing_add(b[0]);
-    a[0] = c;
-    a[1] = a[1] + b[1] + d as u128;
-}
-
-#[inline]
-fn sub_noborrow(a: &mut [u128; 2], b: &[u128; 2]) {
-    let (c, d) = a[0].overflowing_sub(b[0]);
-    a[0] = c;
-    a[1] = a[1] - b[1] - d as u128;
-}
-
-// TODO: Make `from_index` a const param
-#[inline(always)]
-fn mac_digit(from_index: usize, acc: &mut [u128; 4], b: &[u128; 2], c: u128) {
-    #[inline]
-    fn mac_with_carry(a: u128, b: u128, c: u128, carry: &mut u128) -> u128 {
-        let (b_hi, b_lo) = split_u128(b);
-        let (c_hi, c_lo) = split_u128(c);
-
-        let (a_hi, a_lo) = split_u128(a);
-        let (carry_hi, carry_lo) = split_u128(*carry);
-        let (x_hi, x_lo) = split_u128(b_lo * c_lo + a_lo + carry_lo);
-        let (y_hi, y_lo) = split_u128(b_lo * c_hi);
-        let (z_hi, z_lo) = split_u128(b_hi * c_lo);
-        // Brackets to allow better ILP
-        let (r_hi, r_lo) = split_u128((x_hi + y_lo) + (z_lo + a_hi) + carry_hi);
-
-        *carry = (b_hi * c_hi) + r_hi + y_hi + z_hi;
-
-        combine_u128(r_lo, x_lo)
-    }
-
-    if c == 0 {
-        return;
-    }
-
-    let mut carry = 0;
-
-    debug_assert_eq!(acc.len(), 4);
-    unroll! {
-        for i in 0..2 {
-            let a_index = i + from_index;
-            acc[a_index] = mac_with_carry(acc[a_index], b[i], c, &mut carry);
-        }
-    }
-    unroll! {
-        for i in 0..2 {
-            let a_index = i + from_index + 2;
-            if a_index < 4 {
-                let (a, b) = acc[a_index].overflowing_add(carry);
-                acc[a_index] = a;
-                carry = b as u128;
-            }
-        }
-    }
-
-    debug_assert!(carry == 0);
-}
-
-#[inline]
-fn mul_reduce(this: &mut [u128; 2], by: &[u128; 2], modulus: &[u128; 2], inv: u128) {
-    // The Montgomery reduction here is based on Algorithm 14.32 in
-    // Handbook of Applied Cryptography
-    // <http://cacr.uwaterloo.ca/hac/about/chap14.pdf>.
-
-    let mut res = [0; 2 * 2];
-    unroll! {
-        for i in 0..2 {
-            mac_digit(i, &mut res, by, this[i]);
-        }
-    }
-
-    unroll! {
-        for i in 0..2 {
-            let k = inv.wrapping_mul(res[i]);
-            mac_digit(i, &mut res, modulus, k);
-        }
-    }
-
-    this.copy_from_slice(&res[2..]);
-}
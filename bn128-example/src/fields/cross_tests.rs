@@ -0,0 +1,60 @@
+// Checks the `#[rvv_vector]` field operations against the scalar ones, on
+// whichever backend the binary was built for (the simulator or real RVV).
+
+use super::{FieldElement, Fq, Fr};
+
+pub fn entry() {
+    test_fq_vector_ops();
+    test_fr_vector_ops();
+}
+
+// xorshift64*, only used to spread the operands over the field
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545f4914f6cdd1d)
+    }
+
+    fn fill(&mut self, buf: &mut [u8; 64]) {
+        for chunk in buf.chunks_mut(8) {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes());
+        }
+    }
+}
+
+macro_rules! cross_test {
+    ($test:ident, $field:ident, $seed:expr) => {
+        pub fn $test() {
+            let mut rng = Rng($seed);
+            let mut values = [$field::zero(); 32];
+            values[1] = $field::one();
+            values[2] = $field::one().scalar_add($field::one());
+            for v in values[3..16].iter_mut() {
+                let mut buf = [0u8; 64];
+                rng.fill(&mut buf);
+                *v = $field::interpret(&buf);
+            }
+            // Negations make `a + b` land exactly on the modulus and
+            // `a - b` on zero, the edges of the final subtractions
+            for i in 0..16 {
+                values[16 + i] = values[i].scalar_neg();
+            }
+
+            for &a in values.iter() {
+                assert_eq!(a.vector_neg(), a.scalar_neg());
+                for &b in values.iter() {
+                    assert_eq!(a.vector_add(b), a.scalar_add(b));
+                    assert_eq!(a.vector_sub(b), a.scalar_sub(b));
+                    assert_eq!(a.vector_mul(b), a.scalar_mul(b));
+                }
+            }
+        }
+    };
+}
+
+cross_test!(test_fq_vector_ops, Fq, 0x9e3779b97f4a7c15);
+cross_test!(test_fr_vector_ops, Fr, 0xd1b54a32d192ed03);
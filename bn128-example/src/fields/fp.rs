@@ -1,11 +1,12 @@
 use crate::arith::{U256, U512};
 use crate::fields::FieldElement;
-use crate::rvv_impl::mont_multi_asm;
+use crate::rvv_impl::{fp_add, fp_neg, fp_sub, mont_multi, mont_multi_asm, mul_reduce_internal};
 use core::intrinsics::transmute;
 use core::ops::{Add, Mul, Neg, Sub};
+use rvv_simulator_runtime::{MontgomeryParams, Uint};
 
 macro_rules! field_impl {
-    ($name:ident, $modulus:expr, $rsquared:expr, $rcubed:expr, $one:expr, $inv:expr) => {
+    ($name:ident, $modulus:expr, $rsquared:expr, $rcubed:expr, $one:expr, $np:expr) => {
         #[derive(Copy, Clone, Default, PartialEq, Eq, Debug)]
         #[repr(C)]
         pub struct $name(U256);
@@ -13,13 +14,20 @@ macro_rules! field_impl {
         impl From<$name> for U256 {
             #[inline]
             fn from(mut a: $name) -> Self {
-                a.0.mul(&U256::one(), &U256($modulus), $inv);
+                $name::mont_mul(&mut a.0, &U256::one());
 
                 a.0
             }
         }
 
         impl $name {
+            const PARAMS: MontgomeryParams<4> = MontgomeryParams::from_raw_parts(
+                Uint($modulus),
+                $np[0],
+                Uint($one),
+                Uint($rsquared),
+            );
+
             pub fn from_str(s: &str) -> Option<Self> {
                 let mut ints = [Self::zero(); 11];
                 for i in 1..11 {
@@ -43,8 +51,8 @@ macro_rules! field_impl {
 
             /// Converts a U256 to an Fp so long as it's below the modulus.
             pub fn new(mut a: U256) -> Option<Self> {
-                if a < U256($modulus) {
-                    a.mul(&U256($rsquared), &U256($modulus), $inv);
+                if a < U256::from($modulus) {
+                    $name::mont_mul(&mut a, &U256::from($rsquared));
 
                     Some($name(a))
                 } else {
@@ -54,25 +62,26 @@ macro_rules! field_impl {
 
             /// Converts a U256 to an Fr regardless of modulus.
             pub fn new_mul_factor(mut a: U256) -> Self {
-                a.mul(&U256($rsquared), &U256($modulus), $inv);
+                $name::mont_mul(&mut a, &U256::from($rsquared));
                 $name(a)
             }
 
             pub fn interpret(buf: &[u8; 64]) -> Self {
-                $name::new(U512::interpret(buf).divrem(&U256($modulus)).1).unwrap()
+                $name::new(U512::interpret(buf).divrem(&U256::from($modulus)).1).unwrap()
             }
 
             /// Returns the modulus
             #[inline]
             #[allow(dead_code)]
             pub fn modulus() -> U256 {
-                U256($modulus)
+                U256::from($modulus)
             }
 
+            /// Returns the Montgomery parameters of the field
             #[inline]
             #[allow(dead_code)]
-            pub fn inv(&self) -> u128 {
-                $inv
+            pub fn params() -> &'static MontgomeryParams<4> {
+                &Self::PARAMS
             }
 
             pub fn raw(&self) -> &U256 {
@@ -82,17 +91,83 @@ macro_rules! field_impl {
             pub fn set_bit(&mut self, bit: usize, to: bool) {
                 self.0.set_bit(bit, to);
             }
+
+            /// Montgomery multiplication on the backend selected by features.
+            #[inline]
+            fn mont_mul(a: &mut U256, b: &U256) {
+                if cfg!(feature = "use_rvv_vector") {
+                    a.0 = mont_multi(Uint($np), Uint($modulus), a.0, b.0, 256);
+                } else if cfg!(feature = "use_rvv_asm") {
+                    mul_reduce_internal(&mut a.0, &b.0, &Uint($modulus), &Uint($np));
+                } else {
+                    a.mul(b, &Self::PARAMS);
+                }
+            }
+
+            // Both implementations of the ring operations stay callable in
+            // every build, so they can be cross-tested against each other.
+            // The operators pick one by the `use_rvv_vector` feature.
+
+            #[inline]
+            pub fn vector_add(self, other: $name) -> $name {
+                $name(U256(fp_add(Uint($modulus), (self.0).0, (other.0).0)))
+            }
+
+            #[inline]
+            pub fn vector_sub(self, other: $name) -> $name {
+                $name(U256(fp_sub(Uint($modulus), (self.0).0, (other.0).0)))
+            }
+
+            #[inline]
+            pub fn vector_mul(self, other: $name) -> $name {
+                $name(U256(mont_multi(
+                    Uint($np),
+                    Uint($modulus),
+                    (self.0).0,
+                    (other.0).0,
+                    256,
+                )))
+            }
+
+            #[inline]
+            pub fn vector_neg(self) -> $name {
+                $name(U256(fp_neg(Uint($modulus), (self.0).0)))
+            }
+
+            #[inline]
+            pub fn scalar_add(mut self, other: $name) -> $name {
+                self.0.add(&other.0, &U256::from($modulus));
+                self
+            }
+
+            #[inline]
+            pub fn scalar_sub(mut self, other: $name) -> $name {
+                self.0.sub(&other.0, &U256::from($modulus));
+                self
+            }
+
+            #[inline]
+            pub fn scalar_mul(mut self, other: $name) -> $name {
+                self.0.mul(&other.0, &Self::PARAMS);
+                self
+            }
+
+            #[inline]
+            pub fn scalar_neg(mut self) -> $name {
+                self.0.neg(&U256::from($modulus));
+                self
+            }
         }
 
         impl FieldElement for $name {
             #[inline]
             fn zero() -> Self {
-                $name(U256([0, 0]))
+                $name(U256::zero())
             }
 
             #[inline]
             fn one() -> Self {
-                $name(U256($one))
+                $name(U256::from($one))
             }
 
             #[inline]
@@ -104,8 +179,8 @@ macro_rules! field_impl {
                 if self.is_zero() {
                     None
                 } else {
-                    self.0.invert(&U256($modulus));
-                    self.0.mul(&U256($rcubed), &U256($modulus), $inv);
+                    self.0.invert(&U256::from($modulus));
+                    $name::mont_mul(&mut self.0, &U256::from($rcubed));
 
                     Some(self)
                 }
@@ -116,10 +191,12 @@ macro_rules! field_impl {
             type Output = $name;
 
             #[inline]
-            fn add(mut self, other: $name) -> $name {
-                self.0.add(&other.0, &U256($modulus));
-
-                self
+            fn add(self, other: $name) -> $name {
+                if cfg!(feature = "use_rvv_vector") {
+                    self.vector_add(other)
+                } else {
+                    self.scalar_add(other)
+                }
             }
         }
 
@@ -127,10 +204,12 @@ macro_rules! field_impl {
             type Output = $name;
 
             #[inline]
-            fn sub(mut self, other: $name) -> $name {
-                self.0.sub(&other.0, &U256($modulus));
-
-                self
+            fn sub(self, other: $name) -> $name {
+                if cfg!(feature = "use_rvv_vector") {
+                    self.vector_sub(other)
+                } else {
+                    self.scalar_sub(other)
+                }
             }
         }
 
@@ -139,7 +218,7 @@ macro_rules! field_impl {
 
             #[inline]
             fn mul(mut self, other: $name) -> $name {
-                self.0.mul(&other.0, &U256($modulus), $inv);
+                $name::mont_mul(&mut self.0, &other.0);
 
                 self
             }
@@ -149,9 +228,12 @@ macro_rules! field_impl {
             type Output = $name;
 
             #[inline]
-            fn neg(mut self) -> $name {
-                self.0.neg(&U256($modulus));
-                self
+            fn neg(self) -> $name {
+                if cfg!(feature = "use_rvv_vector") {
+                    self.vector_neg()
+                } else {
+                    self.scalar_neg()
+                }
             }
         }
     };
@@ -160,51 +242,74 @@ macro_rules! field_impl {
 field_impl!(
     Fr,
     [
-        0x2833e84879b9709143e1f593f0000001,
-        0x30644e72e131a029b85045b68181585d
+        0x43e1f593f0000001,
+        0x2833e84879b97091,
+        0xb85045b68181585d,
+        0x30644e72e131a029
     ],
     [
-        0x53fe3ab1e35c59e31bb8e645ae216da7,
-        0x0216d0b17f4e44a58c49833d53bb8085
+        0x1bb8e645ae216da7,
+        0x53fe3ab1e35c59e3,
+        0x8c49833d53bb8085,
+        0x0216d0b17f4e44a5
     ],
     [
-        0x2a489cbe1cfbb6b85e94d8e1b4bf0040,
-        0x0cf8594b7fcc657c893cc664a19fcfed
+        0x5e94d8e1b4bf0040,
+        0x2a489cbe1cfbb6b8,
+        0x893cc664a19fcfed,
+        0x0cf8594b7fcc657c
     ],
     [
-        0x36fc76959f60cd29ac96341c4ffffffb,
-        0x0e0a77c19a07df2f666ea36f7879462e
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0x0e0a77c19a07df2f
     ],
-    0x6586864b4c6911b3c2e1f593efffffff
+    [
+        0xc2e1f593efffffff,
+        0x6586864b4c6911b3,
+        0xe39a982899062391,
+        0x73f82f1d0d8341b2
+    ]
 );
 
 field_impl!(
     Fq,
     [
-        0x97816a916871ca8d3c208c16d87cfd47,
-        0x30644e72e131a029b85045b68181585d
+        0x3c208c16d87cfd47,
+        0x97816a916871ca8d,
+        0xb85045b68181585d,
+        0x30644e72e131a029
     ],
     [
-        0xb5e71911d44501fbf32cfc5b538afa89,
-        0x06d89f71cab8351f47ab1eff0a417ff6
+        0xf32cfc5b538afa89,
+        0xb5e71911d44501fb,
+        0x47ab1eff0a417ff6,
+        0x06d89f71cab8351f
     ],
     [
-        0x62f210e6a7283db6b1cd6dafda1530df,
-        0x20fd6e902d592544ef7f0b0c0ada0afb
+        0xb1cd6dafda1530df,
+        0x62f210e6a7283db6,
+        0xef7f0b0c0ada0afb,
+        0x20fd6e902d592544
     ],
     [
-        0x0a78eb28f5c70b3dd35d438dc58f0d9d,
-        0x0e0a77c19a07df2f666ea36f7879462c
+        0xd35d438dc58f0d9d,
+        0x0a78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0x0e0a77c19a07df2f
     ],
-    0x9ede7d651eca6ac987d20782e4866389
+    [
+        0x87d20782e4866389,
+        0x9ede7d651eca6ac9,
+        0xd8afcbd01833da80,
+        0xf57a22b791888c6b
+    ]
 );
 
 lazy_static::lazy_static! {
 
-    static ref FQ: U256 = U256([
-        0x97816a916871ca8d3c208c16d87cfd47,
-        0x30644e72e131a029b85045b68181585d
-    ]);
+    static ref FQ: U256 = Fq::modulus();
 
     pub static ref FQ_MINUS3_DIV4: Fq =
         Fq::new(3.into()).expect("3 is a valid field element and static; qed").neg() *
@@ -240,34 +345,37 @@ pub fn const_fq(i: [u64; 4]) -> Fq {
     Fq(U256::from(i))
 }
 
-const NP1: [u128; 2] = [
-    0x9ede7d651eca6ac987d20782e4866389,
-    0xf57a22b791888c6bd8afcbd01833da80,
-];
+const NP1: Uint<4> = Uint([
+    0x87d20782e4866389,
+    0x9ede7d651eca6ac9,
+    0xd8afcbd01833da80,
+    0xf57a22b791888c6b,
+]);
 
-const NP1_ARRAY: [[u128; 2]; 32] = [
+const NP1_ARRAY: [Uint<4>; 32] = [
     NP1, NP1, NP1, NP1, NP1, NP1, NP1, NP1, NP1, NP1, NP1, NP1, NP1, NP1, NP1, NP1, NP1, NP1, NP1,
     NP1, NP1, NP1, NP1, NP1, NP1, NP1, NP1, NP1, NP1, NP1, NP1, NP1,
 ];
 
-const N: [u128; 2] = [
-    0x97816a916871ca8d3c208c16d87cfd47,
-    0x30644e72e131a029b85045b68181585d,
-];
+const N: Uint<4> = Uint([
+    0x3c208c16d87cfd47,
+    0x97816a916871ca8d,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+]);
 
-const N_ARRAY: [[u128; 2]; 32] = [
+const N_ARRAY: [Uint<4>; 32] = [
     N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N, N,
 ];
 
 pub fn batch_mul(q1: &[Fq], q2: &[Fq], result: &mut [Fq]) {
     unsafe {
-        let qq1 = transmute::<&[Fq], &[[u128; 2]]>(q1);
-        let qq2 = transmute::<&[Fq], &[[u128; 2]]>(q2);
-        let result2 = transmute::<&mut [Fq], &mut [[u128; 2]]>(result);
+        let qq1 = transmute::<&[Fq], &[Uint<4>]>(q1);
+        let qq2 = transmute::<&[Fq], &[Uint<4>]>(q2);
+        let result2 = transmute::<&mut [Fq], &mut [Uint<4>]>(result);
         mont_multi_asm(&NP1_ARRAY, &N_ARRAY, qq1, qq2, result2);
     }
 }
-
 pub fn batch_mul2(lhs1: Fq, lhs2: Fq, rhs1: Fq, rhs2: Fq) -> (Fq, Fq) {
     let mut result: [Fq; 2] = [Fq::zero(), Fq::zero()];
     let lhs = [lhs1, lhs2];
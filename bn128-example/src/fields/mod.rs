@@ -1,3 +1,4 @@
+pub mod cross_tests;
 mod fp;
 mod fq12;
 mod fq2;
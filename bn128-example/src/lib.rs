@@ -8,6 +8,7 @@ mod groups;
 pub mod rvv_crypto;
 pub mod rvv_impl;
 
+pub use crate::fields::cross_tests as fields_cross_tests;
 use crate::fields::FieldElement;
 use crate::groups::{G1Params, G2Params, GroupElement, GroupParams};
 use core::ops::{Add, Mul, Neg, Sub};
@@ -153,11 +154,7 @@ impl Fq {
     pub fn to_big_endian(&self, slice: &mut [u8]) -> Result<(), FieldError> {
         let mut a: arith::U256 = self.0.into();
         // convert from Montgomery representation
-        a.mul(
-            &fields::Fq::one().raw(),
-            &fields::Fq::modulus(),
-            self.0.inv(),
-        );
+        a.mul(&fields::Fq::one().raw(), fields::Fq::params());
         a.to_big_endian(slice)
             .map_err(|_| FieldError::InvalidSliceLength)
     }
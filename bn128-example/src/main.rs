@@ -58,6 +58,11 @@ pub fn program_entry(argc: u64, argv: *const *const u8) -> i8 {
             bench_mont();
             debug(format!("bench_mont done"));
             return 0;
+        } else if arg1 == "fields_cross_tests" {
+            debug(format!("start fields cross tests"));
+            alt_bn128_example::fields_cross_tests::entry();
+            debug(format!("fields cross tests done"));
+            return 0;
         } else if arg1 == "rvv_crypto_tests" {
             debug(format!("start rvv-crypto tests"));
             alt_bn128_example::rvv_crypto::cross_tests::entry();
@@ -98,7 +98,7 @@ impl From<Gfp> for U256 {
     fn from(a: Gfp) -> Self {
         let mut arr = [a];
         normalize(&mut arr);
-        arr[0].clone().into()
+        U256::from(arr[0].0)
     }
 }
 
@@ -1,4 +1,4 @@
-use core::{mem::transmute, slice::from_raw_parts};
+use core::slice::from_ref;
 use rvv::rvv_vector;
 use rvv_asm::rvv_asm;
 use rvv_simulator_runtime::Uint;
@@ -45,14 +45,40 @@ pub fn mont_multi(np1: U256, n: U256, x: U256, y: U256, bits: usize) -> U256 {
     mont_reduce(np1, n, xy, bits)
 }
 
+// Field operations on reduced operands, for moduli below 2^255 so the sums
+// can't wrap. Together with `mont_multi` they back the `use_rvv_vector` Fq/Fr.
+#[rvv_vector]
+pub fn fp_add(n: U256, a: U256, b: U256) -> U256 {
+    let s: U256 = a + b;
+    if s >= n {
+        s - n
+    } else {
+        s
+    }
+}
+
+#[rvv_vector]
+pub fn fp_sub(n: U256, a: U256, b: U256) -> U256 {
+    if a >= b {
+        a - b
+    } else {
+        a + n - b
+    }
+}
+
+#[rvv_vector]
+pub fn fp_neg(n: U256, a: U256) -> U256 {
+    // zero maps to `n`, which folds back to zero
+    let r: U256 = n - a;
+    if r >= n {
+        r - n
+    } else {
+        r
+    }
+}
+
 #[inline(never)]
-pub fn mont_multi_asm(
-    np1: &[[u128; 2]],
-    n: &[[u128; 2]],
-    x: &[[u128; 2]],
-    y: &[[u128; 2]],
-    res: &mut [[u128; 2]],
-) {
+pub fn mont_multi_asm(np1: &[U256], n: &[U256], x: &[U256], y: &[U256], res: &mut [U256]) {
     let len = x.len() as u64;
     // not loop version.
     debug_assert!(len <= 32);
@@ -125,49 +151,29 @@ pub fn mont_multi_asm(
     }
 }
 
-#[inline(always)]
-fn from_u128pair(n: &[u128; 2]) -> U256 {
-    let buf: &[u64; 4] = unsafe { transmute(n) };
-    Uint::<4>(buf.clone())
-}
-
-pub fn mul_reduce_internal(
-    this: &mut [u128; 2],
-    by: &[u128; 2],
-    modulus: &[u128; 2],
-    inv: u128,
-    inv_high: u128,
-) {
-    if cfg!(feature = "use_rvv_vector") {
-        let x: U256 = from_u128pair(this);
-        let y: U256 = from_u128pair(by);
-        let n: U256 = from_u128pair(modulus);
-        let np1: U256 = from_u128pair(&[inv, inv_high]);
-        let Uint::<4>(ref result) = mont_multi(np1, n, x, y, 256);
-        this[0] = result[0] as u128 | (result[1] as u128) << 64;
-        this[1] = result[2] as u128 | (result[3] as u128) << 64;
-    } else {
-        unsafe {
-            let mut result = [[0; 2]];
-            mont_multi_asm(
-                &[[inv, inv_high]],
-                from_raw_parts(modulus as *const [u128; 2], 1),
-                from_raw_parts(this as *const [u128; 2], 1),
-                from_raw_parts(by as *const [u128; 2], 1),
-                &mut result,
-            );
-            *this = result[0];
-        }
-    }
+pub fn mul_reduce_internal(this: &mut U256, by: &U256, modulus: &U256, np: &U256) {
+    let mut result = [U256::zero()];
+    mont_multi_asm(
+        from_ref(np),
+        from_ref(modulus),
+        from_ref(&*this),
+        from_ref(by),
+        &mut result,
+    );
+    *this = result[0];
 }
 
 pub fn bench_mont() {
-    let mut this = [0x1234567890ABCDEF1234567890ABCDEF, 0x111111111111111111];
-    let by = [0x1234567891111111, 0x12345678922222222];
-    let modulus = [0x123456789001, 0x1234567892];
-    let inv = 0x123456789;
-    let inv_high = 0x12345678;
+    let mut this = U256!([
+        0x1234567890ABCDEF,
+        0x1234567890ABCDEF,
+        0x1111111111111111,
+        0x11
+    ]);
+    let by = U256!([0x1234567891111111, 0, 0x2345678922222222, 0x1]);
+    let modulus = U256!([0x123456789001, 0, 0x1234567892, 0]);
+    let np = U256!([0x123456789, 0, 0x12345678, 0]);
     for _ in 0..72650 {
-        mul_reduce_internal(&mut this, &by, &modulus, inv, inv_high);
+        mul_reduce_internal(&mut this, &by, &modulus, &np);
     }
 }
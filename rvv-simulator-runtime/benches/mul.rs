@@ -0,0 +1,62 @@
+// Compares `Uint::widening_mul` (schoolbook/Karatsuba) with the schoolbook
+// product it replaced, for the common sizes.
+//
+//     cargo bench --bench mul
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+use rvv_simulator_runtime::Uint;
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 50_000;
+const RUNS: u32 = 8;
+
+// Keeps the optimizer from dropping or hoisting the work
+fn black_box<T>(x: T) -> T {
+    let ret = unsafe { std::ptr::read_volatile(&x) };
+    std::mem::forget(x);
+    ret
+}
+
+fn operands<const N: usize>() -> (Uint<N>, Uint<N>) {
+    let mut xs = common::uints(common::SEED);
+    (xs.next().unwrap(), xs.next().unwrap())
+}
+
+fn time<F: FnMut()>(mut f: F) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        f();
+    }
+    start.elapsed() / ITERATIONS
+}
+
+fn bench<const N: usize>(name: &str) {
+    let (a, b) = operands::<N>();
+    assert_eq!(a.widening_mul(b), common::schoolbook(a, b));
+    // Best of a few interleaved runs, so both sides see the same noise
+    let (mut new, mut old) = (Duration::MAX, Duration::MAX);
+    for _ in 0..RUNS {
+        new = new.min(time(|| {
+            black_box(black_box(a).widening_mul(black_box(b)));
+        }));
+        old = old.min(time(|| {
+            black_box(common::schoolbook(black_box(a), black_box(b)));
+        }));
+    }
+    println!(
+        "{:>6}: widening_mul {:>8?}  schoolbook {:>8?}  speedup {:.2}x",
+        name,
+        new,
+        old,
+        old.as_secs_f64() / new.as_secs_f64()
+    );
+}
+
+fn main() {
+    bench::<4>("U256");
+    bench::<8>("U512");
+    bench::<16>("U1024");
+    bench::<32>("U2048");
+    bench::<64>("U4096");
+}
@@ -1,3 +1,8 @@
 #!/bin/bash
 
+# no_std without alloc
+cargo build
 cargo test --features=std
+cargo test --features=std,ct_trace --test ct_tests
+cargo test --features=std,serde --test codec_tests
+cargo test --features=std,rand,proptest --test random_tests
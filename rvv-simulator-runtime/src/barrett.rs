@@ -0,0 +1,150 @@
+// Barrett reduction over `Uint<N>`
+//
+// For one-off reductions of double-width values, where converting in and out
+// of Montgomery form costs more than it saves. The modulus `m` is shifted left
+// by `s` until its top bit is set, `m' = m << s`, and with `K = 64 * N` and
+// `mu = floor(4^K / m')` every `x = r * 2^K + c` with `r < m'` reduces as
+//
+//     q = (r * mu) >> K
+//     x - q * m'                 (< 4m', at most 3 subtractions left)
+//
+// using only multiplications, shifts and compares. A double-width value is
+// shifted left by `s` as well, which spills into at most one more word, so
+// `reduce_wide` always takes two steps whatever the size of `m`, and shifts
+// the result back down at the end. `mu` has `K + 1` bits; its top bit is
+// implied and only the low `K` bits are stored.
+use crate::uint::Uint;
+
+/// Precomputed constants for Barrett reduction modulo a `Uint<N>`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Barrett<const N: usize> {
+    modulus: Uint<N>,
+    bits: usize,
+    mu: Uint<N>,
+}
+
+impl<const N: usize> Barrett<N> {
+    /// Derives the Barrett constant for `modulus`. Returns `None` if
+    /// `modulus` is zero.
+    pub fn new(modulus: Uint<N>) -> Option<Self> {
+        if modulus.is_zero() {
+            return None;
+        }
+        let bits = modulus.bits();
+        let m = modulus << (N * 64 - bits);
+        // floor(4^K / m') - 2^K == floor(2^K * (2^K - m') / m'), one bit at a time
+        let d = (!m).wrapping_add(Uint::one());
+        let mu = if d == m {
+            // m' == 2^(K-1): mu - 2^K == 2^K doesn't fit, and rounding it down
+            // keeps the error bound
+            Uint::MAX
+        } else {
+            let mut rem = d;
+            let mut mu = Uint::zero();
+            for i in (0..N * 64).rev() {
+                let carry = rem.bit(N * 64 - 1);
+                rem <<= 1;
+                if carry || rem >= m {
+                    rem = rem.wrapping_sub(m);
+                    mu.set_bit(i, true);
+                }
+            }
+            mu
+        };
+        Some(Barrett { modulus, bits, mu })
+    }
+
+    /// The modulus `m`.
+    pub fn modulus(&self) -> Uint<N> {
+        self.modulus
+    }
+
+    /// Bit length `n` of the modulus.
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    /// `floor(2^(64*N + n) / m) - 2^(64*N)`, the Barrett constant for the
+    /// shifted modulus without its implied top bit.
+    pub fn mu(&self) -> Uint<N> {
+        self.mu
+    }
+
+    /// Returns `x mod m`.
+    pub fn reduce(&self, x: Uint<N>) -> Uint<N> {
+        self.reduce_wide(x, Uint::zero())
+    }
+
+    /// Returns `(hi * 2^(64*N) + lo) mod m`.
+    pub fn reduce_wide(&self, lo: Uint<N>, hi: Uint<N>) -> Uint<N> {
+        // (x << s) mod m' == (x mod m) << s; the top word is below 2^s <= m'
+        let s = N * 64 - self.bits;
+        if s == 0 {
+            let r = self.step(Uint::zero(), hi);
+            return self.step(r, lo);
+        }
+        let top = hi >> (N * 64 - s);
+        let mid = (hi << s) | (lo >> (N * 64 - s));
+        let r = self.step(top, mid);
+        self.step(r, lo << s) >> s
+    }
+
+    /// Returns `(a * b) mod m`.
+    pub fn mul_mod(&self, a: Uint<N>, b: Uint<N>) -> Uint<N> {
+        let (lo, hi) = a.widening_mul(b);
+        self.reduce_wide(lo, hi)
+    }
+
+    /// `reduce` over a slice, writing into `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths.
+    pub fn reduce_slice(&self, xs: &[Uint<N>], out: &mut [Uint<N>]) {
+        assert_eq!(xs.len(), out.len());
+        for (o, x) in out.iter_mut().zip(xs) {
+            *o = self.reduce(*x);
+        }
+    }
+
+    // Reduces `r * 2^K + c` modulo m', with `r < m'`
+    fn step(&self, r: Uint<N>, c: Uint<N>) -> Uint<N> {
+        let m = self.modulus << (N * 64 - self.bits);
+        // q = (r * (2^K + mu)) >> K, never above the true quotient (< 2^K)
+        let q = r + r.widening_mul(self.mu).1;
+        // x - q * m', double width until it's below m'
+        let (qm_lo, qm_hi) = q.widening_mul(m);
+        let (mut lo, borrow) = c.overflowing_sub(qm_lo);
+        let mut hi = r.wrapping_sub(qm_hi);
+        if borrow {
+            hi = hi.wrapping_sub(Uint::one());
+        }
+        while !hi.is_zero() || lo >= m {
+            let (d, borrow) = lo.overflowing_sub(m);
+            lo = d;
+            if borrow {
+                hi = hi.wrapping_sub(Uint::one());
+            }
+        }
+        lo
+    }
+}
+macro_rules! barrett_full {
+    ($small:tt, $big:tt) => {
+        impl Barrett<$small> {
+            /// Reduces a double-width value, such as the result of
+            /// `Uint::full_mul`.
+            pub fn reduce_full(&self, x: Uint<$big>) -> Uint<$small> {
+                let mut lo = [0; $small];
+                let mut hi = [0; $small];
+                lo.copy_from_slice(&x.0[..$small]);
+                hi.copy_from_slice(&x.0[$small..]);
+                self.reduce_wide(Uint(lo), Uint(hi))
+            }
+        }
+    };
+}
+
+barrett_full!(4, 8);
+barrett_full!(8, 16);
+barrett_full!(16, 32);
@@ -0,0 +1,138 @@
+// Element-wise operations over slices of `Uint<N>`
+//
+// These are the portable scalar versions of what the RVV examples do a
+// vector register group at a time. A vector backend can replace any of them
+// behind the same signatures, so callers don't each invent their own batch
+// API. Like the vector instructions, the arithmetic wraps instead of
+// panicking, and comparisons produce a bitmask with one bit per element
+// (element `i` is bit `i % 64` of word `i / 64`).
+//
+// Every function panics if the slices have different lengths, or if a mask
+// is shorter than `mask_len(len)` words.
+use crate::core_::cmp::Ordering;
+use crate::uint::Uint;
+
+/// Number of `u64` words in a mask covering `len` elements.
+// `div_ceil` isn't available on the pinned toolchain
+#[allow(unknown_lints, clippy::manual_div_ceil)]
+pub fn mask_len(len: usize) -> usize {
+    (len + 63) / 64
+}
+
+/// Returns bit `i` of `mask`.
+pub fn mask_bit(mask: &[u64], i: usize) -> bool {
+    (mask[i / 64] >> (i % 64)) & 1 == 1
+}
+
+macro_rules! binary_slice {
+    ($(#[$doc:meta] $name:ident, $assign_name:ident, $method:ident;)*) => {
+        $(
+            #[$doc]
+            pub fn $name<const N: usize>(a: &[Uint<N>], b: &[Uint<N>], out: &mut [Uint<N>]) {
+                assert_eq!(a.len(), b.len());
+                assert_eq!(b.len(), out.len());
+                for ((o, x), y) in out.iter_mut().zip(a).zip(b) {
+                    *o = x.$method(*y);
+                }
+            }
+
+            /// In-place version: `a[i] = op(a[i], b[i])`.
+            pub fn $assign_name<const N: usize>(a: &mut [Uint<N>], b: &[Uint<N>]) {
+                assert_eq!(a.len(), b.len());
+                for (x, y) in a.iter_mut().zip(b) {
+                    *x = x.$method(*y);
+                }
+            }
+        )*
+    };
+}
+
+binary_slice! {
+    /// `out[i] = a[i] + b[i]`, wrapping.
+    add_slice, add_assign_slice, wrapping_add;
+    /// `out[i] = a[i] - b[i]`, wrapping.
+    sub_slice, sub_assign_slice, wrapping_sub;
+    /// `out[i] = a[i] * b[i]`, wrapping.
+    mul_slice, mul_assign_slice, wrapping_mul;
+}
+
+macro_rules! binary_mod_slice {
+    ($(#[$doc:meta] $name:ident, $method:ident;)*) => {
+        $(
+            #[$doc]
+            ///
+            /// # Panics
+            ///
+            /// Panics if `modulus` is zero.
+            pub fn $name<const N: usize>(
+                a: &[Uint<N>],
+                b: &[Uint<N>],
+                modulus: Uint<N>,
+                out: &mut [Uint<N>],
+            ) {
+                assert_eq!(a.len(), b.len());
+                assert_eq!(b.len(), out.len());
+                for ((o, x), y) in out.iter_mut().zip(a).zip(b) {
+                    *o = x.$method(*y, modulus);
+                }
+            }
+        )*
+    };
+}
+
+binary_mod_slice! {
+    /// `out[i] = (a[i] + b[i]) mod modulus`.
+    add_mod_slice, add_mod;
+    /// `out[i] = (a[i] - b[i]) mod modulus`.
+    sub_mod_slice, sub_mod;
+    /// `out[i] = (a[i] * b[i]) mod modulus`.
+    mul_mod_slice, mul_mod;
+}
+
+/// `out[i] = (lo, hi)` halves of the full product `a[i] * b[i]`.
+pub fn widening_mul_slice<const N: usize>(
+    a: &[Uint<N>],
+    b: &[Uint<N>],
+    out_lo: &mut [Uint<N>],
+    out_hi: &mut [Uint<N>],
+) {
+    assert_eq!(a.len(), b.len());
+    assert_eq!(b.len(), out_lo.len());
+    assert_eq!(out_lo.len(), out_hi.len());
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        let (lo, hi) = x.widening_mul(*y);
+        out_lo[i] = lo;
+        out_hi[i] = hi;
+    }
+}
+
+/// Sets bit `i` of `mask` if `a[i].cmp(&b[i]) == ord`, clears it otherwise.
+/// Bits past the end of the slices are cleared.
+pub fn cmp_slice<const N: usize>(a: &[Uint<N>], b: &[Uint<N>], ord: Ordering, mask: &mut [u64]) {
+    assert_eq!(a.len(), b.len());
+    let words = mask_len(a.len());
+    assert!(mask.len() >= words, "mask too short");
+    for m in mask[..words].iter_mut() {
+        *m = 0;
+    }
+    for (i, (x, y)) in a.iter().zip(b).enumerate() {
+        if x.cmp(y) == ord {
+            mask[i / 64] |= 1 << (i % 64);
+        }
+    }
+}
+
+/// `out[i] = b[i]` where bit `i` of `mask` is set, `a[i]` otherwise.
+pub fn select_slice<const N: usize>(
+    mask: &[u64],
+    a: &[Uint<N>],
+    b: &[Uint<N>],
+    out: &mut [Uint<N>],
+) {
+    assert_eq!(a.len(), b.len());
+    assert_eq!(b.len(), out.len());
+    assert!(mask.len() >= mask_len(a.len()), "mask too short");
+    for (i, ((o, x), y)) in out.iter_mut().zip(a).zip(b).enumerate() {
+        *o = if mask_bit(mask, i) { *y } else { *x };
+    }
+}
@@ -0,0 +1,189 @@
+// Byte encodings of `Uint<N>` beyond the fixed-size big/little-endian ones,
+// and the `serde` implementations
+use crate::core_::fmt;
+use crate::uint::Uint;
+
+/// Error decoding a `Uint` from bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    /// Input has the wrong number of bytes for the type
+    InvalidLength,
+    /// Minimal encoding starts with a zero byte
+    LeadingZero,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                DecodeError::InvalidLength => "invalid number of bytes for the type",
+                DecodeError::LeadingZero => "minimal encoding has a leading zero byte",
+            }
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
+
+impl<const N: usize> Uint<N> {
+    /// Number of bytes in the minimal big-endian encoding: 0 for zero.
+    pub fn minimal_be_len(&self) -> usize {
+        N * 8 - self.leading_zeros() as usize / 8
+    }
+
+    /// Writes the big-endian bytes without leading zeros, as RLP encodes
+    /// integers, to the start of `out` and returns how many were written.
+    /// Zero is encoded as no bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is shorter than `minimal_be_len()`.
+    pub fn write_minimal_be(&self, out: &mut [u8]) -> usize {
+        let len = self.minimal_be_len();
+        for (i, b) in out[..len].iter_mut().enumerate() {
+            *b = self.byte(len - 1 - i);
+        }
+        len
+    }
+
+    /// Decodes the minimal big-endian encoding written by `write_minimal_be`.
+    /// Leading zero bytes are rejected, so every value has exactly one
+    /// encoding.
+    pub fn from_minimal_be(bytes: &[u8]) -> Result<Self, DecodeError> {
+        if bytes.len() > N * 8 {
+            return Err(DecodeError::InvalidLength);
+        }
+        if bytes.first() == Some(&0) {
+            return Err(DecodeError::LeadingZero);
+        }
+        Ok(Self::from_be_bytes_iter(bytes.iter().copied()))
+    }
+
+    /// Writes the SSZ encoding of a `uint(64 * N)`: exactly `8 * N`
+    /// little-endian bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` isn't `8 * N` bytes long.
+    pub fn write_ssz(&self, out: &mut [u8]) {
+        assert_eq!(out.len(), N * 8);
+        for (i, b) in out.iter_mut().enumerate() {
+            *b = self.byte(i);
+        }
+    }
+
+    /// Decodes the SSZ encoding written by `write_ssz`.
+    pub fn from_ssz(bytes: &[u8]) -> Result<Self, DecodeError> {
+        if bytes.len() != N * 8 {
+            return Err(DecodeError::InvalidLength);
+        }
+        Ok(Self::from_be_bytes_iter(bytes.iter().rev().copied()))
+    }
+
+    // Shifts in big-endian bytes; there must be at most `8 * N` of them
+    fn from_be_bytes_iter<I: Iterator<Item = u8>>(bytes: I) -> Self {
+        let mut ret = [0u64; N];
+        for b in bytes {
+            for i in (1..N).rev() {
+                ret[i] = (ret[i] << 8) | (ret[i - 1] >> 56);
+            }
+            ret[0] = (ret[0] << 8) | b as u64;
+        }
+        Uint(ret)
+    }
+}
+
+// Human-readable formats get a `0x`-prefixed hex string without leading
+// zeros, the others exactly `8 * N` big-endian bytes. The bytes are copied
+// into a stack buffer first, which caps compact serialization at
+// `MAX_COMPACT_WORDS`; deserialization works at every size.
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::Uint;
+    use crate::core_::fmt;
+    use serde::de::{self, Deserialize, Deserializer, SeqAccess, Visitor};
+    use serde::ser::{self, Serialize, Serializer};
+
+    // Largest `N` compact serialization takes, the same as the fixed byte
+    // arrays
+    const MAX_COMPACT_WORDS: usize = 64;
+
+    impl<const N: usize> Serialize for Uint<N> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            if serializer.is_human_readable() {
+                serializer.collect_str(&format_args!("{:#x}", self))
+            } else {
+                if N > MAX_COMPACT_WORDS {
+                    return Err(ser::Error::custom(format_args!(
+                        "compact serialization supports at most {} words, not {}",
+                        MAX_COMPACT_WORDS, N
+                    )));
+                }
+                let mut buf = [0u8; 8 * MAX_COMPACT_WORDS];
+                for (chunk, w) in buf.chunks_mut(8).zip(self.0.iter().rev()) {
+                    chunk.copy_from_slice(&w.to_be_bytes());
+                }
+                serializer.serialize_bytes(&buf[..8 * N])
+            }
+        }
+    }
+
+    struct HexVisitor<const N: usize>;
+
+    impl<'de, const N: usize> Visitor<'de> for HexVisitor<N> {
+        type Value = Uint<N>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "a hex string of at most {} digits", N * 16)
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Uint<N>, E> {
+            Uint::from_str_radix(v, 16).map_err(E::custom)
+        }
+    }
+
+    struct BytesVisitor<const N: usize>;
+
+    impl<'de, const N: usize> Visitor<'de> for BytesVisitor<N> {
+        type Value = Uint<N>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write!(f, "{} big-endian bytes", N * 8)
+        }
+
+        fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Uint<N>, E> {
+            if v.len() != N * 8 {
+                return Err(E::invalid_length(v.len(), &self));
+            }
+            Ok(Uint::from_be_bytes_iter(v.iter().copied()))
+        }
+
+        // Formats without a byte string type send a sequence of `u8`
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Uint<N>, A::Error> {
+            let mut ret = Uint::<N>::zero();
+            for i in 0..N * 8 {
+                let b: u8 = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+                ret = (ret << 8) | Uint::from(b as u64);
+            }
+            if seq.next_element::<de::IgnoredAny>()?.is_some() {
+                return Err(de::Error::invalid_length(N * 8 + 1, &self));
+            }
+            Ok(ret)
+        }
+    }
+
+    impl<'de, const N: usize> Deserialize<'de> for Uint<N> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            if deserializer.is_human_readable() {
+                deserializer.deserialize_str(HexVisitor)
+            } else {
+                deserializer.deserialize_bytes(BytesVisitor)
+            }
+        }
+    }
+}
@@ -0,0 +1,194 @@
+// Constant-time operations on `Uint<N>`
+//
+// The regular `Uint` methods take shortcuts on the values (early returns in
+// comparisons, `if carry != 0` in subtraction, skipping zero bits in `pow`),
+// which leaks timing in RSA and pairing code. Everything here only loops
+// over the fixed word/bit count and combines results with masks.
+use crate::core_::ops::{BitAnd, BitOr, Not};
+use crate::uint::Uint;
+
+#[cfg(feature = "ct_trace")]
+macro_rules! trace {
+    ($op:expr) => {
+        crate::ct::trace::record($op)
+    };
+}
+
+#[cfg(not(feature = "ct_trace"))]
+macro_rules! trace {
+    ($op:expr) => {};
+}
+
+/// Records the word operations executed by the constant-time code and by the
+/// kernels it calls (multiplication, Montgomery reduction), so tests can
+/// check that different inputs produce identical traces.
+///
+/// Only code with `trace!` calls shows up: a data-dependent branch elsewhere
+/// goes unnoticed.
+#[cfg(feature = "ct_trace")]
+pub mod trace {
+    use std::cell::RefCell;
+    use std::vec::Vec;
+
+    // `const` thread-local initializers aren't available on the pinned toolchain
+    std::thread_local! {
+        #[allow(unknown_lints, clippy::missing_const_for_thread_local)]
+        static TRACE: RefCell<Vec<&'static str>> = RefCell::new(Vec::new());
+    }
+
+    pub(crate) fn record(op: &'static str) {
+        TRACE.with(|t| t.borrow_mut().push(op));
+    }
+
+    /// Returns the operations recorded on this thread since the last call
+    /// and clears the log.
+    pub fn take() -> Vec<&'static str> {
+        TRACE.with(|t| t.replace(Vec::new()))
+    }
+}
+
+/// Result of a constant-time predicate: 1 for true, 0 for false.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Choice(u64);
+
+impl Choice {
+    /// Returns 1 or 0.
+    pub fn unwrap_u64(self) -> u64 {
+        self.0
+    }
+
+    // All ones for 1, all zeros for 0
+    fn mask(self) -> u64 {
+        0u64.wrapping_sub(self.0)
+    }
+}
+
+impl From<bool> for Choice {
+    fn from(b: bool) -> Self {
+        Choice(b as u64)
+    }
+}
+
+impl From<Choice> for bool {
+    fn from(c: Choice) -> Self {
+        c.0 != 0
+    }
+}
+
+impl Not for Choice {
+    type Output = Choice;
+
+    fn not(self) -> Choice {
+        Choice(self.0 ^ 1)
+    }
+}
+
+impl BitAnd for Choice {
+    type Output = Choice;
+
+    fn bitand(self, rhs: Choice) -> Choice {
+        Choice(self.0 & rhs.0)
+    }
+}
+
+impl BitOr for Choice {
+    type Output = Choice;
+
+    fn bitor(self, rhs: Choice) -> Choice {
+        Choice(self.0 | rhs.0)
+    }
+}
+
+/// Operations whose control flow and memory accesses don't depend on the
+/// values involved.
+pub trait ConstantTime: Sized {
+    /// `self == other`.
+    fn ct_eq(&self, other: &Self) -> Choice;
+
+    /// `self < other`.
+    fn ct_lt(&self, other: &Self) -> Choice;
+
+    /// Returns `a` if `choice` is 0 and `b` if it's 1.
+    fn ct_select(a: &Self, b: &Self, choice: Choice) -> Self;
+
+    /// Swaps `a` and `b` if `choice` is 1.
+    fn ct_swap(a: &mut Self, b: &mut Self, choice: Choice);
+
+    /// Returns `self - other` (wrapping) if `choice` is 1, `self` otherwise.
+    fn ct_conditional_sub(&self, other: &Self, choice: Choice) -> Self;
+
+    /// Returns `self^expon`, wrapping on overflow, using a Montgomery ladder
+    /// over every bit of `expon`.
+    fn ct_pow(&self, expon: &Self) -> Self;
+}
+
+impl<const N: usize> Uint<N> {
+    // Wrapping subtraction and the final borrow, without branching on it.
+    fn ct_sub_borrow(&self, other: &Self) -> (Self, Choice) {
+        let mut ret = [0u64; N];
+        let mut borrow = 0u64;
+        for i in 0..N {
+            trace!("sub");
+            let (d1, b1) = self.0[i].overflowing_sub(other.0[i]);
+            let (d2, b2) = d1.overflowing_sub(borrow);
+            ret[i] = d2;
+            borrow = (b1 | b2) as u64;
+        }
+        (Uint(ret), Choice(borrow))
+    }
+}
+
+impl<const N: usize> ConstantTime for Uint<N> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let mut diff = 0u64;
+        for i in 0..N {
+            trace!("xor");
+            diff |= self.0[i] ^ other.0[i];
+        }
+        // top bit of `diff | -diff` is set iff diff != 0
+        Choice(((diff | diff.wrapping_neg()) >> 63) ^ 1)
+    }
+
+    fn ct_lt(&self, other: &Self) -> Choice {
+        self.ct_sub_borrow(other).1
+    }
+
+    fn ct_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mask = choice.mask();
+        let mut ret = [0u64; N];
+        for i in 0..N {
+            trace!("select");
+            ret[i] = a.0[i] ^ (mask & (a.0[i] ^ b.0[i]));
+        }
+        Uint(ret)
+    }
+
+    fn ct_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        let mask = choice.mask();
+        for i in 0..N {
+            trace!("swap");
+            let t = mask & (a.0[i] ^ b.0[i]);
+            a.0[i] ^= t;
+            b.0[i] ^= t;
+        }
+    }
+
+    fn ct_conditional_sub(&self, other: &Self, choice: Choice) -> Self {
+        let (diff, _) = self.ct_sub_borrow(other);
+        Self::ct_select(self, &diff, choice)
+    }
+
+    fn ct_pow(&self, expon: &Self) -> Self {
+        let mut r0 = Self::one();
+        let mut r1 = *self;
+        // invariant: r1 == r0 * self
+        for i in (0..N * 64).rev() {
+            let bit = Choice((expon.0[i / 64] >> (i % 64)) & 1);
+            Self::ct_swap(&mut r0, &mut r1, bit);
+            r1 = r0.widening_mul(r1).0;
+            r0 = r0.widening_mul(r0).0;
+            Self::ct_swap(&mut r0, &mut r1, bit);
+        }
+        r0
+    }
+}
@@ -0,0 +1,521 @@
+// Signed two's-complement integer over the same limbs as `Uint<N>`
+//
+// Arithmetic follows the primitive signed integers: `+`, `-`, `*` and `/`
+// panic on overflow (like `Uint`), division and remainder truncate toward
+// zero, and `>>` is an arithmetic shift.
+use crate::core_::cmp::Ordering;
+use crate::core_::convert::TryFrom;
+use crate::core_::fmt;
+use crate::core_::ops::{
+    Add, AddAssign, BitAnd, BitOr, BitXor, Div, DivAssign, Mul, MulAssign, Neg, Not, Rem,
+    RemAssign, Shl, Shr, Sub, SubAssign,
+};
+use crate::uint::Uint;
+
+/// Signed integer of `N` 64-bit words in two's complement.
+#[repr(transparent)]
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Default)]
+pub struct Int<const N: usize>(Uint<N>);
+
+/// The error type returned when a signed/unsigned conversion is out of range.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TryFromIntError(());
+
+impl fmt::Display for TryFromIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "out of range integral type conversion attempted")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromIntError {}
+
+impl<const N: usize> Int<N> {
+    /// Largest value, `2^(64N - 1) - 1`.
+    pub const MAX: Self = {
+        let mut arr = [u64::MAX; N];
+        arr[N - 1] = u64::MAX >> 1;
+        Int(Uint(arr))
+    };
+    /// Smallest value, `-2^(64N - 1)`.
+    pub const MIN: Self = {
+        let mut arr = [0; N];
+        arr[N - 1] = 1 << 63;
+        Int(Uint(arr))
+    };
+
+    /// Reinterprets the bits of a `Uint`.
+    pub const fn from_bits(bits: Uint<N>) -> Self {
+        Int(bits)
+    }
+
+    /// The underlying two's-complement bits.
+    pub const fn to_bits(self) -> Uint<N> {
+        self.0
+    }
+
+    pub fn zero() -> Self {
+        Int(Uint::zero())
+    }
+
+    pub fn one() -> Self {
+        Int(Uint::one())
+    }
+
+    pub fn minus_one() -> Self {
+        Int(Uint::MAX)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub const fn is_negative(&self) -> bool {
+        self.0 .0[N - 1] >> 63 == 1
+    }
+
+    pub fn is_positive(&self) -> bool {
+        !self.is_negative() && !self.is_zero()
+    }
+
+    /// -1, 0 or 1 depending on the sign.
+    pub fn signum(&self) -> Self {
+        if self.is_negative() {
+            Self::minus_one()
+        } else if self.is_zero() {
+            Self::zero()
+        } else {
+            Self::one()
+        }
+    }
+
+    /// Absolute value as a `Uint`, which can't overflow.
+    pub fn unsigned_abs(self) -> Uint<N> {
+        if self.is_negative() {
+            self.0.wrapping_neg()
+        } else {
+            self.0
+        }
+    }
+
+    /// Absolute value with overflow; only `MIN` overflows.
+    pub fn overflowing_abs(self) -> (Self, bool) {
+        (Int(self.unsigned_abs()), self == Self::MIN)
+    }
+
+    /// Checked absolute value. Returns `None` for `MIN`.
+    pub fn checked_abs(self) -> Option<Self> {
+        match self.overflowing_abs() {
+            (_, true) => None,
+            (val, false) => Some(val),
+        }
+    }
+
+    pub fn wrapping_abs(self) -> Self {
+        self.overflowing_abs().0
+    }
+
+    /// Absolute value.
+    ///
+    /// # Panics
+    ///
+    /// Panics for `MIN`.
+    pub fn abs(self) -> Self {
+        let (res, overflow) = self.overflowing_abs();
+        crate::panic_on_overflow!(overflow);
+        res
+    }
+
+    pub fn overflowing_neg(self) -> (Self, bool) {
+        (Int(self.0.wrapping_neg()), self == Self::MIN)
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        match self.overflowing_neg() {
+            (_, true) => None,
+            (val, false) => Some(val),
+        }
+    }
+
+    pub fn wrapping_neg(self) -> Self {
+        self.overflowing_neg().0
+    }
+
+    pub fn overflowing_add(self, other: Self) -> (Self, bool) {
+        let res = Int(self.0.wrapping_add(other.0));
+        // Overflows iff both operands have the same sign and the result doesn't
+        let overflow =
+            self.is_negative() == other.is_negative() && res.is_negative() != self.is_negative();
+        (res, overflow)
+    }
+
+    pub fn overflowing_sub(self, other: Self) -> (Self, bool) {
+        let res = Int(self.0.wrapping_sub(other.0));
+        let overflow =
+            self.is_negative() != other.is_negative() && res.is_negative() != self.is_negative();
+        (res, overflow)
+    }
+
+    pub fn overflowing_mul(self, other: Self) -> (Self, bool) {
+        // The low half of the product is the same for signed and unsigned
+        let res = Int(self.0.wrapping_mul(other.0));
+        let negative = self.is_negative() != other.is_negative();
+        let (magnitude, overflow) = self.unsigned_abs().overflowing_mul(other.unsigned_abs());
+        let limit = if negative { Self::MIN.0 } else { Self::MAX.0 };
+        (res, overflow || magnitude > limit)
+    }
+
+    /// Returns `(self / other, self % other)`, truncating toward zero, and
+    /// whether it overflowed (only `MIN / -1` does).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    pub fn overflowing_div_rem(self, other: Self) -> ((Self, Self), bool) {
+        let (q, r) = self.unsigned_abs().div_mod(other.unsigned_abs());
+        let q = if self.is_negative() != other.is_negative() {
+            q.wrapping_neg()
+        } else {
+            q
+        };
+        let r = if self.is_negative() {
+            r.wrapping_neg()
+        } else {
+            r
+        };
+        let overflow = self == Self::MIN && other == Self::minus_one();
+        ((Int(q), Int(r)), overflow)
+    }
+
+    /// Returns `(self / other, self % other)`, truncating toward zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero or on `MIN / -1`.
+    pub fn div_rem(self, other: Self) -> (Self, Self) {
+        let (res, overflow) = self.overflowing_div_rem(other);
+        crate::panic_on_overflow!(overflow);
+        res
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        match self.overflowing_add(other) {
+            (_, true) => None,
+            (val, false) => Some(val),
+        }
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.overflowing_sub(other) {
+            (_, true) => None,
+            (val, false) => Some(val),
+        }
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        match self.overflowing_mul(other) {
+            (_, true) => None,
+            (val, false) => Some(val),
+        }
+    }
+
+    /// Checked division. Returns `None` if `other == 0` or on `MIN / -1`.
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        match self.overflowing_div_rem(other) {
+            (_, true) => None,
+            ((q, _), false) => Some(q),
+        }
+    }
+
+    /// Checked remainder. Returns `None` if `other == 0` or on `MIN % -1`.
+    pub fn checked_rem(self, other: Self) -> Option<Self> {
+        if other.is_zero() {
+            return None;
+        }
+        match self.overflowing_div_rem(other) {
+            (_, true) => None,
+            ((_, r), false) => Some(r),
+        }
+    }
+
+    pub fn wrapping_add(self, other: Self) -> Self {
+        self.overflowing_add(other).0
+    }
+
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        self.overflowing_sub(other).0
+    }
+
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        self.overflowing_mul(other).0
+    }
+}
+
+impl<const N: usize> Ord for Int<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_negative(), other.is_negative()) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            // Same sign: two's complement orders like the unsigned bits
+            _ => self.0.cmp(&other.0),
+        }
+    }
+}
+
+impl<const N: usize> PartialOrd for Int<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> fmt::Display for Int<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.unsigned_abs()
+            .fmt_radix_signed(f, self.is_negative(), 10, false, "")
+    }
+}
+
+impl<const N: usize> fmt::Debug for Int<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+// Like the primitive types, the radix formatters print the two's-complement bits
+macro_rules! impl_int_fmt {
+    ($($trait:ident),*) => {
+        $(
+            impl<const N: usize> fmt::$trait for Int<N> {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    fmt::$trait::fmt(&self.0, f)
+                }
+            }
+        )*
+    };
+}
+
+impl_int_fmt!(LowerHex, UpperHex, Octal, Binary);
+
+impl<const N: usize> Neg for Int<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        let (res, overflow) = self.overflowing_neg();
+        crate::panic_on_overflow!(overflow);
+        res
+    }
+}
+
+impl<const N: usize> Not for Int<N> {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Int(!self.0)
+    }
+}
+
+macro_rules! impl_int_arith {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, $op:ident;)*) => {
+        $(
+            impl<const N: usize> $trait for Int<N> {
+                type Output = Self;
+
+                fn $method(self, other: Self) -> Self {
+                    let (res, overflow) = self.$op(other);
+                    crate::panic_on_overflow!(overflow);
+                    res
+                }
+            }
+
+            impl<const N: usize> $assign_trait for Int<N> {
+                fn $assign_method(&mut self, other: Self) {
+                    *self = $trait::$method(*self, other);
+                }
+            }
+        )*
+    };
+}
+
+impl_int_arith! {
+    Add, add, AddAssign, add_assign, overflowing_add;
+    Sub, sub, SubAssign, sub_assign, overflowing_sub;
+    Mul, mul, MulAssign, mul_assign, overflowing_mul;
+}
+
+impl<const N: usize> Div for Int<N> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self.div_rem(other).0
+    }
+}
+
+impl<const N: usize> DivAssign for Int<N> {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<const N: usize> Rem for Int<N> {
+    type Output = Self;
+
+    fn rem(self, other: Self) -> Self {
+        self.div_rem(other).1
+    }
+}
+
+impl<const N: usize> RemAssign for Int<N> {
+    fn rem_assign(&mut self, other: Self) {
+        *self = *self % other;
+    }
+}
+
+macro_rules! impl_int_bitwise {
+    ($($trait:ident, $method:ident;)*) => {
+        $(
+            impl<const N: usize> $trait for Int<N> {
+                type Output = Self;
+
+                fn $method(self, other: Self) -> Self {
+                    Int($trait::$method(self.0, other.0))
+                }
+            }
+        )*
+    };
+}
+
+impl_int_bitwise! {
+    BitAnd, bitand;
+    BitOr, bitor;
+    BitXor, bitxor;
+}
+
+impl<T, const N: usize> Shl<T> for Int<N>
+where
+    T: Into<Uint<N>>,
+{
+    type Output = Self;
+
+    fn shl(self, shift: T) -> Self {
+        Int(self.0 << shift)
+    }
+}
+
+impl<T, const N: usize> Shr<T> for Int<N>
+where
+    T: Into<Uint<N>>,
+{
+    type Output = Self;
+
+    /// Arithmetic shift: the sign bit is copied into the vacated bits.
+    fn shr(self, shift: T) -> Self {
+        if self.is_negative() {
+            Int(!(!self.0 >> shift))
+        } else {
+            Int(self.0 >> shift)
+        }
+    }
+}
+
+// Types up to 64 bits fit in any `Int<N>`
+macro_rules! impl_from_signed {
+    ($($t:ty),*) => {
+        $(
+            impl<const N: usize> From<$t> for Int<N> {
+                fn from(value: $t) -> Self {
+                    // Sign-extend into the upper words
+                    let mut arr = [((value as i64) >> 63) as u64; N];
+                    arr[0] = value as i64 as u64;
+                    Int(Uint(arr))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_signed!(i8, i16, i32, i64, isize);
+
+// `i128` takes two words, so `Int<1>` only gets a checked conversion
+macro_rules! impl_from_i128 {
+    ($($n:tt)*) => {
+        $(
+            impl From<i128> for Int<$n> {
+                fn from(value: i128) -> Self {
+                    let mut arr = [(value >> 127) as u64; $n];
+                    arr[0] = value as u64;
+                    arr[1] = (value >> 64) as u64;
+                    Int(Uint(arr))
+                }
+            }
+        )*
+    };
+}
+
+impl_from_i128!(2 4 8 16 32 64);
+
+impl TryFrom<i128> for Int<1> {
+    type Error = TryFromIntError;
+
+    fn try_from(value: i128) -> Result<Self, TryFromIntError> {
+        i64::try_from(value)
+            .map(Int::from)
+            .map_err(|_| TryFromIntError(()))
+    }
+}
+
+macro_rules! impl_try_into_signed {
+    ($($t:ty),*) => {
+        $(
+            impl<const N: usize> TryFrom<Int<N>> for $t {
+                type Error = TryFromIntError;
+
+                fn try_from(value: Int<N>) -> Result<$t, TryFromIntError> {
+                    let words = &value.0 .0;
+                    // The low two words, sign-extended from the first one
+                    // when there's only one
+                    let low = match words.get(1) {
+                        Some(&hi) => ((hi as u128) << 64 | words[0] as u128) as i128,
+                        None => words[0] as i64 as i128,
+                    };
+                    let sign = (low >> 127) as u64;
+                    if words.iter().skip(2).any(|&w| w != sign) {
+                        return Err(TryFromIntError(()));
+                    }
+                    <$t>::try_from(low).map_err(|_| TryFromIntError(()))
+                }
+            }
+        )*
+    };
+}
+
+impl_try_into_signed!(i8, i16, i32, i64, isize, i128);
+
+impl<const N: usize> TryFrom<Uint<N>> for Int<N> {
+    type Error = TryFromIntError;
+
+    /// Fails if the value has the top bit set.
+    fn try_from(value: Uint<N>) -> Result<Self, TryFromIntError> {
+        let res = Int(value);
+        if res.is_negative() {
+            Err(TryFromIntError(()))
+        } else {
+            Ok(res)
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<Int<N>> for Uint<N> {
+    type Error = TryFromIntError;
+
+    /// Fails if the value is negative.
+    fn try_from(value: Int<N>) -> Result<Self, TryFromIntError> {
+        if value.is_negative() {
+            Err(TryFromIntError(()))
+        } else {
+            Ok(value.0)
+        }
+    }
+}
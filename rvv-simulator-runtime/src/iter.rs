@@ -0,0 +1,64 @@
+// Reducing iterators of `Uint<N>`
+use crate::core_::borrow::Borrow;
+use crate::core_::iter::{Product, Sum};
+use crate::uint::Uint;
+
+// Like the primitive integers, these panic on overflow
+impl<const N: usize> Sum for Uint<N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
+impl<'a, const N: usize> Sum<&'a Uint<N>> for Uint<N> {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + *x)
+    }
+}
+
+impl<const N: usize> Product for Uint<N> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, x| acc * x)
+    }
+}
+
+impl<'a, const N: usize> Product<&'a Uint<N>> for Uint<N> {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), |acc, x| acc * *x)
+    }
+}
+
+/// Sums with an explicit overflow policy, for iterators over `Uint<N>` or
+/// `&Uint<N>`.
+pub trait UintSum<const N: usize>: Iterator + Sized
+where
+    Self::Item: Borrow<Uint<N>>,
+{
+    /// Sum of the items, or `None` as soon as it overflows.
+    fn checked_sum(mut self) -> Option<Uint<N>> {
+        self.try_fold(Uint::zero(), |acc, x| acc.checked_add(*x.borrow()))
+    }
+
+    /// Sum of the items, wrapping on overflow.
+    fn wrapping_sum(self) -> Uint<N> {
+        self.fold(Uint::zero(), |acc, x| acc.wrapping_add(*x.borrow()))
+    }
+
+    /// Sum of the items mod `modulus`. Items don't need to be reduced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    fn mod_sum(self, modulus: Uint<N>) -> Uint<N> {
+        self.fold(Uint::zero() % modulus, |acc, x| {
+            acc.add_mod(*x.borrow(), modulus)
+        })
+    }
+}
+
+impl<I, const N: usize> UintSum<N> for I
+where
+    I: Iterator,
+    I::Item: Borrow<Uint<N>>,
+{
+}
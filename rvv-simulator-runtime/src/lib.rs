@@ -8,14 +8,33 @@
 #[doc(hidden)]
 pub use core as core_;
 
-#[doc(hidden)]
-pub use byteorder;
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 #[doc(hidden)]
-pub use hex;
+pub use byteorder;
 
 #[doc(hidden)]
 pub use static_assertions;
 
+mod barrett;
+pub mod batch;
+mod codec;
+#[macro_use]
+pub mod ct;
+mod int;
+mod iter;
+mod modular;
+mod montgomery;
+mod mul;
+mod number_theory;
+#[cfg(any(feature = "rand", feature = "proptest"))]
+mod random;
 mod uint;
+pub use crate::barrett::Barrett;
+pub use crate::codec::DecodeError;
+pub use crate::ct::{Choice, ConstantTime};
+pub use crate::int::{Int, TryFromIntError};
+pub use crate::iter::UintSum;
+pub use crate::montgomery::{Montgomery, MontgomeryParams};
 pub use crate::uint::*;
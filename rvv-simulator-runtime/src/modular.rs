@@ -0,0 +1,182 @@
+// Generic modular arithmetic on `Uint<N>`
+//
+// These are the portable reference versions: the inputs don't need to be
+// reduced, and every result is in `[0, modulus)`.
+use crate::uint::Uint;
+
+impl<const N: usize> Uint<N> {
+    /// Returns `(self + other) mod modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn add_mod(self, other: Self, modulus: Self) -> Self {
+        let a = self % modulus;
+        let b = other % modulus;
+        let (sum, overflow) = a.overflowing_add(b);
+        if overflow || sum >= modulus {
+            sum.wrapping_sub(modulus)
+        } else {
+            sum
+        }
+    }
+
+    /// Returns `(self - other) mod modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn sub_mod(self, other: Self, modulus: Self) -> Self {
+        let a = self % modulus;
+        let b = other % modulus;
+        let (diff, borrow) = a.overflowing_sub(b);
+        if borrow {
+            diff.wrapping_add(modulus)
+        } else {
+            diff
+        }
+    }
+
+    /// Returns `(self * other) mod modulus`, computed from the full
+    /// double-width product so it never overflows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn mul_mod(self, other: Self, modulus: Self) -> Self {
+        let (lo, hi) = self.widening_mul(other);
+        Self::rem_wide(lo, hi, modulus)
+    }
+
+    /// Returns `self^expon mod modulus` (left-to-right square and multiply).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    pub fn pow_mod(self, expon: Self, modulus: Self) -> Self {
+        let base = self % modulus;
+        let mut res = Self::one() % modulus;
+        for i in (0..expon.bits()).rev() {
+            res = res.mul_mod(res, modulus);
+            if expon.bit(i) {
+                res = res.mul_mod(base, modulus);
+            }
+        }
+        res
+    }
+
+    /// Returns `x` such that `self * x mod modulus == 1`, using the extended
+    /// Euclidean algorithm. Returns `None` if `modulus` is zero or `self` and
+    /// `modulus` are not coprime.
+    pub fn inv_mod(self, modulus: Self) -> Option<Self> {
+        if modulus.is_zero() {
+            return None;
+        }
+        // Invariants: r0 == t0 * self, r1 == t1 * self (mod modulus)
+        let mut r0 = modulus;
+        let mut r1 = self % modulus;
+        let mut t0 = Self::zero();
+        let mut t1 = Self::one() % modulus;
+        while !r1.is_zero() {
+            let (q, r) = r0.div_mod(r1);
+            r0 = r1;
+            r1 = r;
+            let t = t0.sub_mod(q.mul_mod(t1, modulus), modulus);
+            t0 = t1;
+            t1 = t;
+        }
+        if r0 == Self::one() {
+            Some(t0)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the square root of `self` modulo the prime `p` (Tonelli-Shanks),
+    /// or `None` if `self` is not a quadratic residue. Of the two roots `r`
+    /// and `p - r`, the smaller one is returned.
+    ///
+    /// The result is unspecified if `p` is not a prime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is zero.
+    pub fn sqrt_mod(self, p: Self) -> Option<Self> {
+        let one = Self::one();
+        let a = self % p;
+        if a.is_zero() || p == Self::from(2u64) {
+            return Some(a);
+        }
+        let p_minus_1 = p - one;
+        let half = p_minus_1 >> 1;
+        // Euler's criterion
+        if a.pow_mod(half, p) != one {
+            return None;
+        }
+
+        // p - 1 = q * 2^s, with q odd
+        let s = p_minus_1.trailing_zeros();
+        let q = p_minus_1 >> s;
+
+        // Any quadratic non-residue z
+        let mut z = Self::from(2u64);
+        while z.pow_mod(half, p) != p_minus_1 {
+            z += one;
+            if z >= p {
+                return None;
+            }
+        }
+
+        let mut m = s;
+        let mut c = z.pow_mod(q, p);
+        let mut t = a.pow_mod(q, p);
+        let mut r = a.pow_mod((q >> 1) + one, p);
+        while t != one {
+            // Least i (0 < i < m) such that t^(2^i) == 1
+            let mut i = 0;
+            let mut t2i = t;
+            while t2i != one {
+                t2i = t2i.mul_mod(t2i, p);
+                i += 1;
+                if i == m {
+                    return None;
+                }
+            }
+            let mut b = c;
+            for _ in 0..m - i - 1 {
+                b = b.mul_mod(b, p);
+            }
+            m = i;
+            c = b.mul_mod(b, p);
+            t = t.mul_mod(c, p);
+            r = r.mul_mod(b, p);
+        }
+        let neg_r = p - r;
+        if neg_r < r {
+            Some(neg_r)
+        } else {
+            Some(r)
+        }
+    }
+
+    // Reduces the double-width value `hi * 2^(64*N) + lo` modulo `modulus`.
+    // The high part is reduced by division, then the bits of the low part
+    // are shifted in one at a time (same as `U512::divrem` in bn128).
+    fn rem_wide(lo: Self, hi: Self, modulus: Self) -> Self {
+        if hi.is_zero() {
+            return lo % modulus;
+        }
+        let mut r = hi % modulus;
+        for i in (0..N * 64).rev() {
+            let carry = r.bit(N * 64 - 1);
+            r <<= 1;
+            if lo.bit(i) {
+                r.0[0] |= 1;
+            }
+            if carry || r >= modulus {
+                r = r.wrapping_sub(modulus);
+            }
+        }
+        r
+    }
+}
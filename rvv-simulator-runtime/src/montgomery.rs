@@ -0,0 +1,376 @@
+// Montgomery arithmetic over `Uint<N>`
+//
+// R is fixed to 2^(64 * N), so the reduction works word by word (CIOS) and
+// only needs `-modulus^-1 mod 2^64`, no signed extended Euclid.
+use crate::core_::fmt;
+use crate::core_::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use crate::ct::{Choice, ConstantTime};
+use crate::uint::Uint;
+
+/// Precomputed constants for Montgomery arithmetic modulo an odd `Uint<N>`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MontgomeryParams<const N: usize> {
+    modulus: Uint<N>,
+    n_prime: u64,
+    r: Uint<N>,
+    r2: Uint<N>,
+}
+
+impl<const N: usize> MontgomeryParams<N> {
+    /// Derives the Montgomery constants for `modulus`. Returns `None` if
+    /// `modulus` is even.
+    pub fn new(modulus: Uint<N>) -> Option<Self> {
+        if !modulus.bit(0) {
+            return None;
+        }
+        // Newton iteration: each step doubles the number of correct low bits.
+        // An odd n is its own inverse modulo 8, so start with 3 bits.
+        let n0 = modulus.0[0];
+        let mut inv = n0;
+        for _ in 0..5 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(n0.wrapping_mul(inv)));
+        }
+        // R mod n == (R - n) mod n, and R - n fits in N words
+        let r = Uint::<N>::zero().wrapping_sub(modulus) % modulus;
+        let r2 = r.mul_mod(r, modulus);
+        Some(MontgomeryParams {
+            modulus,
+            n_prime: inv.wrapping_neg(),
+            r,
+            r2,
+        })
+    }
+
+    /// Builds the parameters from constants computed ahead of time, so they
+    /// can live in a `const`. Nothing is checked: the values must be what
+    /// `new(modulus)` would derive.
+    pub const fn from_raw_parts(modulus: Uint<N>, n_prime: u64, r: Uint<N>, r2: Uint<N>) -> Self {
+        MontgomeryParams {
+            modulus,
+            n_prime,
+            r,
+            r2,
+        }
+    }
+
+    /// The modulus `n`.
+    pub fn modulus(&self) -> Uint<N> {
+        self.modulus
+    }
+
+    /// `-n^-1 mod 2^64`.
+    pub fn n_prime(&self) -> u64 {
+        self.n_prime
+    }
+
+    /// `R mod n`, which is also the Montgomery form of 1.
+    pub fn r(&self) -> Uint<N> {
+        self.r
+    }
+
+    /// `R^2 mod n`.
+    pub fn r2(&self) -> Uint<N> {
+        self.r2
+    }
+
+    /// Montgomery product: returns `a * b * R^-1 mod n`.
+    ///
+    /// `b` must be less than the modulus. `a` can be any `Uint<N>`: the
+    /// result stays below `2n` before the final subtraction as long as
+    /// `a < R` and `b < n`.
+    pub fn mul(&self, a: Uint<N>, b: Uint<N>) -> Uint<N> {
+        let n = &self.modulus.0;
+        let mut t = [0u64; N];
+        // The two words above `t`
+        let mut t_hi = 0u64;
+        for i in 0..N {
+            // t += a * b[i]
+            let mut carry = 0;
+            for j in 0..N {
+                trace!("mac");
+                let (lo, hi) = mac(t[j], a.0[j], b.0[i], carry);
+                t[j] = lo;
+                carry = hi;
+            }
+            let (s, overflow) = t_hi.overflowing_add(carry);
+            t_hi = s;
+            let t_top = overflow as u64;
+
+            // t = (t + m * n) / 2^64, where m makes the lowest word zero
+            let m = t[0].wrapping_mul(self.n_prime);
+            trace!("mac");
+            let (_, mut carry) = mac(t[0], m, n[0], 0);
+            for j in 1..N {
+                trace!("mac");
+                let (lo, hi) = mac(t[j], m, n[j], carry);
+                t[j - 1] = lo;
+                carry = hi;
+            }
+            let (s, overflow) = t_hi.overflowing_add(carry);
+            t[N - 1] = s;
+            t_hi = t_top + overflow as u64;
+        }
+        // Final subtraction without branching on the result, so the
+        // Montgomery product is constant time
+        let t = Uint(t);
+        let keep = Choice::from(t_hi == 0) & t.ct_lt(&self.modulus);
+        t.ct_conditional_sub(&self.modulus, !keep)
+    }
+
+    /// Converts `x` into Montgomery form: `x * R mod n`.
+    pub fn to_mont(&self, x: Uint<N>) -> Uint<N> {
+        self.mul(x % self.modulus, self.r2)
+    }
+
+    /// Converts `x` out of Montgomery form: `x * R^-1 mod n`.
+    pub fn from_mont(&self, x: Uint<N>) -> Uint<N> {
+        self.mul(x, Uint::one())
+    }
+
+    /// `to_mont` over a slice, writing into `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths.
+    pub fn to_mont_slice(&self, xs: &[Uint<N>], out: &mut [Uint<N>]) {
+        assert_eq!(xs.len(), out.len());
+        for (o, x) in out.iter_mut().zip(xs) {
+            *o = self.to_mont(*x);
+        }
+    }
+
+    /// `from_mont` over a slice, writing into `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths.
+    pub fn from_mont_slice(&self, xs: &[Uint<N>], out: &mut [Uint<N>]) {
+        assert_eq!(xs.len(), out.len());
+        for (o, x) in out.iter_mut().zip(xs) {
+            *o = self.from_mont(*x);
+        }
+    }
+
+    /// Element-wise Montgomery product `out[i] = a[i] * b[i] * R^-1 mod n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths.
+    pub fn mul_slice(&self, a: &[Uint<N>], b: &[Uint<N>], out: &mut [Uint<N>]) {
+        assert_eq!(a.len(), b.len());
+        assert_eq!(b.len(), out.len());
+        for ((o, x), y) in out.iter_mut().zip(a).zip(b) {
+            *o = self.mul(*x, *y);
+        }
+    }
+}
+
+// a + b * c + carry, returned as (low, high) words; can't overflow
+#[inline(always)]
+fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let v = a as u128 + (b as u128) * (c as u128) + carry as u128;
+    (v as u64, (v >> 64) as u64)
+}
+
+/// A residue modulo `params.modulus()`, kept in Montgomery form.
+#[derive(Copy, Clone)]
+pub struct Montgomery<const N: usize> {
+    value: Uint<N>,
+    params: MontgomeryParams<N>,
+}
+
+impl<const N: usize> Montgomery<N> {
+    /// Converts `x` into Montgomery form.
+    pub fn new(x: Uint<N>, params: &MontgomeryParams<N>) -> Self {
+        Montgomery {
+            value: params.to_mont(x),
+            params: *params,
+        }
+    }
+
+    /// Wraps a value that's already in Montgomery form (and reduced).
+    pub fn from_mont_repr(value: Uint<N>, params: &MontgomeryParams<N>) -> Self {
+        debug_assert!(value < params.modulus);
+        Montgomery {
+            value,
+            params: *params,
+        }
+    }
+
+    /// The Montgomery form of 0.
+    pub fn zero(params: &MontgomeryParams<N>) -> Self {
+        Self::from_mont_repr(Uint::zero(), params)
+    }
+
+    /// The Montgomery form of 1.
+    pub fn one(params: &MontgomeryParams<N>) -> Self {
+        Self::from_mont_repr(params.r, params)
+    }
+
+    /// The raw Montgomery representation `x * R mod n`.
+    pub fn mont_repr(&self) -> Uint<N> {
+        self.value
+    }
+
+    /// Converts back to the normal representation.
+    pub fn retrieve(&self) -> Uint<N> {
+        self.params.from_mont(self.value)
+    }
+
+    pub fn params(&self) -> &MontgomeryParams<N> {
+        &self.params
+    }
+
+    pub fn square(&self) -> Self {
+        self.derive(self.params.mul(self.value, self.value))
+    }
+
+    /// Returns `self^expon` (left-to-right square and multiply).
+    pub fn pow(&self, expon: Uint<N>) -> Self {
+        let mut res = Self::one(&self.params);
+        for i in (0..expon.bits()).rev() {
+            res = res.square();
+            if expon.bit(i) {
+                res *= *self;
+            }
+        }
+        res
+    }
+
+    /// Returns `self^expon` with a Montgomery ladder over every bit of
+    /// `expon`, so the sequence of operations doesn't depend on the exponent.
+    pub fn ct_pow(&self, expon: Uint<N>) -> Self {
+        let mut r0 = Self::one(&self.params).value;
+        let mut r1 = self.value;
+        for i in (0..N * 64).rev() {
+            let bit = Choice::from(expon.bit(i));
+            Uint::ct_swap(&mut r0, &mut r1, bit);
+            r1 = self.params.mul(r0, r1);
+            r0 = self.params.mul(r0, r0);
+            Uint::ct_swap(&mut r0, &mut r1, bit);
+        }
+        self.derive(r0)
+    }
+
+    /// Multiplicative inverse, or `None` if `self` isn't invertible.
+    pub fn inv(&self) -> Option<Self> {
+        // (xR)^-1 * R^3 * R^-1 == x^-1 * R
+        let inv = self.value.inv_mod(self.params.modulus)?;
+        let r3 = self.params.mul(self.params.r2, self.params.r2);
+        Some(self.derive(self.params.mul(inv, r3)))
+    }
+
+    fn derive(&self, value: Uint<N>) -> Self {
+        Montgomery {
+            value,
+            params: self.params,
+        }
+    }
+}
+
+impl<const N: usize> fmt::Debug for Montgomery<N> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Montgomery")
+            .field("value", &self.retrieve())
+            .field("modulus", &self.params.modulus)
+            .finish()
+    }
+}
+
+impl<const N: usize> PartialEq for Montgomery<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value && self.params.modulus == other.params.modulus
+    }
+}
+
+impl<const N: usize> Eq for Montgomery<N> {}
+
+impl<const N: usize> Add for Montgomery<N> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        debug_assert_eq!(self.params.modulus, rhs.params.modulus);
+        let n = self.params.modulus;
+        let (sum, overflow) = self.value.overflowing_add(rhs.value);
+        if overflow || sum >= n {
+            self.derive(sum.wrapping_sub(n))
+        } else {
+            self.derive(sum)
+        }
+    }
+}
+
+impl<const N: usize> Sub for Montgomery<N> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        debug_assert_eq!(self.params.modulus, rhs.params.modulus);
+        let (diff, borrow) = self.value.overflowing_sub(rhs.value);
+        if borrow {
+            self.derive(diff.wrapping_add(self.params.modulus))
+        } else {
+            self.derive(diff)
+        }
+    }
+}
+
+impl<const N: usize> Mul for Montgomery<N> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        debug_assert_eq!(self.params.modulus, rhs.params.modulus);
+        self.derive(self.params.mul(self.value, rhs.value))
+    }
+}
+
+impl<const N: usize> Neg for Montgomery<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if self.value.is_zero() {
+            self
+        } else {
+            self.derive(self.params.modulus - self.value)
+        }
+    }
+}
+
+macro_rules! impl_mont_ops {
+    ($($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident;)*) => {
+        $(
+            impl<'a, const N: usize> $trait<&'a Montgomery<N>> for Montgomery<N> {
+                type Output = Montgomery<N>;
+
+                fn $method(self, rhs: &'a Montgomery<N>) -> Montgomery<N> {
+                    $trait::$method(self, *rhs)
+                }
+            }
+
+            impl<'a, const N: usize> $trait<&'a Montgomery<N>> for &'a Montgomery<N> {
+                type Output = Montgomery<N>;
+
+                fn $method(self, rhs: &'a Montgomery<N>) -> Montgomery<N> {
+                    $trait::$method(*self, *rhs)
+                }
+            }
+
+            impl<const N: usize> $assign_trait for Montgomery<N> {
+                fn $assign_method(&mut self, rhs: Montgomery<N>) {
+                    *self = $trait::$method(*self, rhs);
+                }
+            }
+
+            impl<'a, const N: usize> $assign_trait<&'a Montgomery<N>> for Montgomery<N> {
+                fn $assign_method(&mut self, rhs: &'a Montgomery<N>) {
+                    *self = $trait::$method(*self, *rhs);
+                }
+            }
+        )*
+    };
+}
+
+impl_mont_ops! {
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+}
@@ -0,0 +1,163 @@
+// Multiplication kernels behind `Uint::widening_mul`
+//
+// Schoolbook up to `KARATSUBA_THRESHOLD` words. Above that, even sizes use
+// subtractive Karatsuba, which replaces one of the four half-size products
+// with a few additions. The halves go back through `widening_mul`, so U2048
+// splits down to 8-word schoolbook products. Odd sizes stay on schoolbook:
+// none of the common `Uint`s have one, and halves of different lengths would
+// need a scratch layout of their own.
+//
+// Neither kernel branches on the operands: loop bounds only depend on `N`,
+// and Karatsuba's signs are applied with masks, so the constant-time code
+// can multiply through here.
+use crate::core_::convert::TryInto;
+
+// Largest size, in words, that is multiplied with schoolbook directly. See
+// `benches/mul.rs`.
+const KARATSUBA_THRESHOLD: usize = 8;
+
+#[inline]
+pub(crate) fn widening_mul<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], [u64; N]) {
+    if N <= KARATSUBA_THRESHOLD || N % 2 == 1 {
+        schoolbook_fixed(a, b)
+    } else {
+        karatsuba(a, b)
+    }
+}
+
+// `schoolbook` with the size known at compile time, so the loops unroll
+#[inline(always)]
+fn schoolbook_fixed<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], [u64; N]) {
+    let mut lo = [0u64; N];
+    let mut hi = [0u64; N];
+    for i in 0..N {
+        let mut carry = 0u64;
+        for j in 0..N {
+            let k = i + j;
+            let w = if k < N { &mut lo[k] } else { &mut hi[k - N] };
+            trace!("mac");
+            let t = a[j] as u128 * b[i] as u128 + *w as u128 + carry as u128;
+            *w = t as u64;
+            carry = (t >> 64) as u64;
+        }
+        // word `i + N` hasn't been written by earlier rows yet
+        hi[i] = carry;
+    }
+    (lo, hi)
+}
+
+// Writes `a * b` (same length n) into `out`, 2n words
+fn schoolbook(a: &[u64], b: &[u64], out: &mut [u64]) {
+    debug_assert_eq!(out.len(), 2 * a.len());
+    for w in out.iter_mut() {
+        *w = 0;
+    }
+    let n = a.len();
+    for i in 0..n {
+        let mut carry = 0u64;
+        for j in 0..n {
+            trace!("mac");
+            let t = a[j] as u128 * b[i] as u128 + out[i + j] as u128 + carry as u128;
+            out[i + j] = t as u64;
+            carry = (t >> 64) as u64;
+        }
+        out[i + n] = carry;
+    }
+}
+
+// `schoolbook`, through `widening_mul` for the sizes Karatsuba splits the
+// common `Uint`s into, so those halves unroll or split again
+fn mul_half(a: &[u64], b: &[u64], out: &mut [u64]) {
+    macro_rules! fixed {
+        ($($n:tt)*) => {
+            match a.len() {
+                $($n => {
+                    let (lo, hi) =
+                        widening_mul::<$n>(a.try_into().unwrap(), b.try_into().unwrap());
+                    out[..$n].copy_from_slice(&lo);
+                    out[$n..].copy_from_slice(&hi);
+                })*
+                _ => schoolbook(a, b, out),
+            }
+        };
+    }
+    fixed!(4 5 6 7 8 16 32)
+}
+
+// One level of Karatsuba, for even `N`
+fn karatsuba<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], [u64; N]) {
+    // a = a1 * B^h + a0
+    let h = N / 2;
+    let (a0, a1) = a.split_at(h);
+    let (b0, b1) = b.split_at(h);
+
+    // z0 = a0 * b0 fills `lo`, z2 = a1 * b1 fills `hi`
+    let mut lo = [0u64; N];
+    let mut hi = [0u64; N];
+    mul_half(a0, b0, &mut lo);
+    mul_half(a1, b1, &mut hi);
+
+    // z1 = a0 * b1 + a1 * b0 = z0 + z2 + (a0 - a1) * (b1 - b0)
+    let mut d = [0u64; N];
+    let (da, db) = d.split_at_mut(h);
+    let sign = abs_diff(a0, a1, da) ^ abs_diff(b1, b0, db);
+    let mut m = [0u64; N];
+    mul_half(da, db, &mut m);
+
+    // A negative `m` is added as its two's complement, sign extended by
+    // `sign` into the top word. z1 < 2 * B^N, so N + 1 words hold it.
+    let mut z1 = [0u64; N];
+    let mut carry = (sign & 1) as u128;
+    for k in 0..N {
+        trace!("add");
+        let s = lo[k] as u128 + hi[k] as u128 + (m[k] ^ sign) as u128 + carry;
+        z1[k] = s as u64;
+        carry = s >> 64;
+    }
+    let z1_top = (carry as u64).wrapping_add(sign);
+
+    // Adds z1 at word h, carrying through to the top
+    let mut carry = 0u128;
+    for k in h..N {
+        trace!("add");
+        let s = lo[k] as u128 + z1[k - h] as u128 + carry;
+        lo[k] = s as u64;
+        carry = s >> 64;
+    }
+    for k in 0..N {
+        let z = match k + N - h {
+            i if i < N => z1[i],
+            i if i == N => z1_top,
+            _ => 0,
+        };
+        trace!("add");
+        let s = hi[k] as u128 + z as u128 + carry;
+        hi[k] = s as u64;
+        carry = s >> 64;
+    }
+    (lo, hi)
+}
+
+// Writes `|x - y|` into `out`, all three the same length. Returns an all-ones
+// mask if `x < y`, zero otherwise; the result is negated with that mask
+// rather than a branch.
+fn abs_diff(x: &[u64], y: &[u64], out: &mut [u64]) -> u64 {
+    let mut borrow = 0u64;
+    for ((o, &xi), &yi) in out.iter_mut().zip(x).zip(y) {
+        trace!("sub");
+        let (d1, b1) = xi.overflowing_sub(yi);
+        let (d2, b2) = d1.overflowing_sub(borrow);
+        *o = d2;
+        borrow = (b1 | b2) as u64;
+    }
+    let mask = borrow.wrapping_neg();
+    // two's complement negation under the mask
+    let mut carry = borrow;
+    for o in out.iter_mut() {
+        trace!("neg");
+        let (s, c) = (*o ^ mask).overflowing_add(carry);
+        *o = s;
+        carry = c as u64;
+    }
+    mask
+}
@@ -0,0 +1,172 @@
+// Number-theory helpers on `Uint<N>`
+use crate::int::Int;
+use crate::montgomery::{Montgomery, MontgomeryParams};
+use crate::uint::Uint;
+
+// Trial divisors, also used as the Miller-Rabin bases. The first 13 primes
+// as bases make the test exact below 3.3 * 10^24 (Sorenson & Webster 2015).
+const SMALL_PRIMES: [u64; 24] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89,
+];
+
+impl<const N: usize> Uint<N> {
+    /// Greatest common divisor (Euclid). `gcd(0, 0) == 0`.
+    pub fn gcd(self, other: Self) -> Self {
+        let mut a = self;
+        let mut b = other;
+        while !b.is_zero() {
+            let r = a % b;
+            a = b;
+            b = r;
+        }
+        a
+    }
+
+    /// Greatest common divisor using Stein's binary algorithm, which only
+    /// needs shifts and subtractions.
+    pub fn binary_gcd(self, other: Self) -> Self {
+        if self.is_zero() {
+            return other;
+        }
+        if other.is_zero() {
+            return self;
+        }
+        let shift = (self | other).trailing_zeros();
+        let mut a = self >> self.trailing_zeros();
+        let mut b = other;
+        while !b.is_zero() {
+            b >>= b.trailing_zeros();
+            if a > b {
+                crate::core_::mem::swap(&mut a, &mut b);
+            }
+            b -= a;
+        }
+        a << shift
+    }
+
+    /// Returns `(g, x, y)` with `g = gcd(self, other)` and
+    /// `self * x + other * y == g`.
+    ///
+    /// The Bezout coefficients are the minimal ones from Euclid's algorithm,
+    /// `|x| <= other / (2g)` and `|y| <= self / (2g)`, so they always fit in
+    /// `Int<N>`.
+    pub fn extended_gcd(self, other: Self) -> (Self, Int<N>, Int<N>) {
+        let (mut r0, mut r1) = (self, other);
+        let (mut s0, mut s1) = (Int::one(), Int::zero());
+        let (mut t0, mut t1) = (Int::zero(), Int::one());
+        while !r1.is_zero() {
+            let (q, r) = r0.div_mod(r1);
+            let q = Int::from_bits(q);
+            r0 = r1;
+            r1 = r;
+            // The coefficients after the last step can be out of range, but
+            // the returned ones aren't, and wrapping keeps them exact
+            let s = s0.wrapping_sub(q.wrapping_mul(s1));
+            s0 = s1;
+            s1 = s;
+            let t = t0.wrapping_sub(q.wrapping_mul(t1));
+            t0 = t1;
+            t1 = t;
+        }
+        (r0, s0, t0)
+    }
+
+    /// Least common multiple. `lcm(x, 0) == 0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows.
+    pub fn lcm(self, other: Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            return Self::zero();
+        }
+        self / self.gcd(other) * other
+    }
+
+    /// Jacobi symbol `(self / n)`: -1, 0 or 1.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is even.
+    pub fn jacobi(self, n: Self) -> i32 {
+        assert!(n.bit(0), "the Jacobi symbol needs an odd modulus");
+        let mut a = self % n;
+        let mut n = n;
+        let mut result = 1;
+        while !a.is_zero() {
+            let twos = a.trailing_zeros();
+            a >>= twos;
+            // (2/n) = -1 iff n = 3, 5 (mod 8)
+            let n_mod_8 = n.low_u64() & 7;
+            if twos % 2 == 1 && (n_mod_8 == 3 || n_mod_8 == 5) {
+                result = -result;
+            }
+            // Quadratic reciprocity
+            if a.low_u64() & 3 == 3 && n.low_u64() & 3 == 3 {
+                result = -result;
+            }
+            let r = n % a;
+            n = a;
+            a = r;
+        }
+        if n == Self::one() {
+            result
+        } else {
+            0
+        }
+    }
+
+    /// Miller-Rabin primality test with the first `rounds` primes as bases,
+    /// after trial division by the first 24 primes. Only 24 bases are
+    /// available, so `rounds` above 24 run 24.
+    ///
+    /// The bases are fixed, so the answer is deterministic: with 13 or more
+    /// rounds it is exact below 3.3 * 10^24, the smallest strong pseudoprime
+    /// to the first 13 prime bases. Above that it is heuristic. Composites
+    /// that pass are rare, but there is no error bound, and one built to
+    /// fool these bases always passes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rounds` is 0.
+    pub fn is_probable_prime(self, rounds: usize) -> bool {
+        assert!(rounds > 0, "is_probable_prime needs at least one round");
+        let rounds = rounds.min(SMALL_PRIMES.len());
+        let n = self;
+        if n < Self::from(2u64) {
+            return false;
+        }
+        for &p in SMALL_PRIMES.iter() {
+            let p = Self::from(p);
+            if n == p {
+                return true;
+            }
+            if (n % p).is_zero() {
+                return false;
+            }
+        }
+
+        // n - 1 = d * 2^s, d odd
+        let n_minus_1 = n - Self::one();
+        let s = n_minus_1.trailing_zeros();
+        let d = n_minus_1 >> s;
+
+        let params = MontgomeryParams::new(n).expect("n is odd here");
+        let one = Montgomery::one(&params);
+        let minus_one = -one;
+        'bases: for &base in SMALL_PRIMES.iter().take(rounds) {
+            let mut x = Montgomery::new(Self::from(base), &params).pow(d);
+            if x == one || x == minus_one {
+                continue;
+            }
+            for _ in 1..s {
+                x = x.square();
+                if x == minus_one {
+                    continue 'bases;
+                }
+            }
+            return false;
+        }
+        true
+    }
+}
@@ -0,0 +1,103 @@
+// Random `Uint<N>` values: `rand` sampling and `proptest` strategies
+
+#[cfg(feature = "rand")]
+mod rand_impl {
+    use crate::uint::Uint;
+    use rand::distributions::{Distribution, Standard};
+    use rand::Rng;
+
+    // Every bit is uniform and independent
+    impl<const N: usize> Distribution<Uint<N>> for Standard {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Uint<N> {
+            let mut ret = [0u64; N];
+            for w in ret.iter_mut() {
+                *w = rng.next_u64();
+            }
+            Uint(ret)
+        }
+    }
+
+    impl<const N: usize> Uint<N> {
+        /// Returns a uniformly random value in `[0, bound)`.
+        ///
+        /// Samples `bound.bits()` random bits until the result is below
+        /// `bound`, which takes fewer than two tries on average.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `bound` is zero.
+        pub fn random_below<R: Rng + ?Sized>(bound: Self, rng: &mut R) -> Self {
+            assert!(!bound.is_zero(), "random_below with a zero bound");
+            let bits = bound.bits();
+            let words = (bits - 1) / 64 + 1;
+            let top_mask = u64::MAX >> (words * 64 - bits);
+            loop {
+                let mut ret = [0u64; N];
+                for w in ret[..words].iter_mut() {
+                    *w = rng.next_u64();
+                }
+                ret[words - 1] &= top_mask;
+                let ret = Uint(ret);
+                if ret < bound {
+                    return ret;
+                }
+            }
+        }
+    }
+}
+
+// Uniform values almost never hit the cases that break multi-word
+// arithmetic, so half of the generated values are edge cases: 0, 1, MAX,
+// powers of two and their neighbours, and values around limb boundaries.
+#[cfg(feature = "proptest")]
+mod proptest_impl {
+    use crate::uint::Uint;
+    use proptest::arbitrary::{any, Arbitrary};
+    use proptest::collection::vec;
+    use proptest::prop_oneof;
+    use proptest::strategy::{BoxedStrategy, Just, Strategy};
+
+    // `base - 1`, `base` or `base + 1`, wrapping
+    fn around<const N: usize>(base: Uint<N>, offset: u8) -> Uint<N> {
+        match offset {
+            0 => base.wrapping_sub(Uint::one()),
+            1 => base,
+            _ => base.wrapping_add(Uint::one()),
+        }
+    }
+
+    impl<const N: usize> Arbitrary for Uint<N> {
+        type Parameters = ();
+        type Strategy = BoxedStrategy<Self>;
+
+        fn arbitrary_with(_: ()) -> Self::Strategy {
+            let uniform = vec(any::<u64>(), N).prop_map(|v| {
+                let mut ret = [0u64; N];
+                ret.copy_from_slice(&v);
+                Uint(ret)
+            });
+            let power_of_two = (0..N * 64, 0..3u8)
+                .prop_map(|(shift, offset)| around(Uint::one() << shift, offset));
+            // A single limb set to an edge value, the others zero or MAX
+            let limb = (
+                0..N,
+                prop_oneof![Just(0), Just(1), Just(u64::MAX), Just(u64::MAX >> 1)],
+                any::<bool>(),
+            )
+                .prop_map(|(i, word, fill)| {
+                    let mut ret = if fill { Uint::MAX } else { Uint::zero() };
+                    ret.0[i] = word;
+                    ret
+                });
+            prop_oneof![
+                5 => uniform,
+                1 => Just(Uint::zero()),
+                1 => Just(Uint::one()),
+                1 => Just(Uint::MAX),
+                1 => power_of_two,
+                1 => limb,
+            ]
+            .boxed()
+        }
+    }
+}
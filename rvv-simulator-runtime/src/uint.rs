@@ -1,6 +1,3 @@
-extern crate alloc;
-
-use alloc::vec::Vec;
 use core::fmt;
 
 use crate::byteorder::{BigEndian, ByteOrder, LittleEndian};
@@ -50,6 +47,10 @@ impl FromStrRadixErr {
         }
     }
 
+    fn new(kind: FromStrRadixErrKind) -> Self {
+        Self { kind, source: None }
+    }
+
     /// Returns the corresponding `FromStrRadixErrKind` for this error.
     pub fn kind(&self) -> FromStrRadixErrKind {
         self.kind
@@ -102,9 +103,8 @@ impl From<FromDecStrErr> for FromStrRadixErr {
 impl From<FromHexError> for FromStrRadixErr {
     fn from(e: FromHexError) -> Self {
         let kind = match e.inner {
-            hex::FromHexError::InvalidHexCharacter { .. } => FromStrRadixErrKind::InvalidCharacter,
-            hex::FromHexError::InvalidStringLength => FromStrRadixErrKind::InvalidLength,
-            hex::FromHexError::OddLength => FromStrRadixErrKind::InvalidLength,
+            HexErrorKind::InvalidCharacter { .. } => FromStrRadixErrKind::InvalidCharacter,
+            HexErrorKind::InvalidLength => FromStrRadixErrKind::InvalidLength,
         };
 
         Self {
@@ -139,30 +139,32 @@ impl fmt::Display for FromDecStrErr {
 #[cfg(feature = "std")]
 impl std::error::Error for FromDecStrErr {}
 
-#[derive(Debug)]
+/// Conversion from hex string error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct FromHexError {
-    inner: hex::FromHexError,
+    inner: HexErrorKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HexErrorKind {
+    // `index` counts from the first digit, after any `0x` prefix
+    InvalidCharacter { c: char, index: usize },
+    InvalidLength,
 }
 
 impl fmt::Display for FromHexError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.inner)
+        match self.inner {
+            HexErrorKind::InvalidCharacter { c, index } => {
+                write!(f, "Invalid character {:?} at position {}", c, index)
+            }
+            HexErrorKind::InvalidLength => write!(f, "Invalid string length"),
+        }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for FromHexError {
-    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
-        Some(&self.inner)
-    }
-}
-
-#[doc(hidden)]
-impl From<hex::FromHexError> for FromHexError {
-    fn from(inner: hex::FromHexError) -> Self {
-        Self { inner }
-    }
-}
+impl std::error::Error for FromHexError {}
 
 #[repr(C)]
 #[derive(Copy, Clone, Eq, PartialEq, Hash)]
@@ -188,6 +190,49 @@ impl<const N: usize> crate::core_::convert::From<i128> for Uint<N> {
     }
 }
 
+/// Builds a `Uint` from a string literal at compile time.
+///
+/// Accepts hex (`0x`), binary (`0b`) and decimal literals, with optional `_`
+/// separators. `uint!(N; "...")` produces a `Uint<N>` through a `const` item,
+/// so a literal that doesn't fit in `N` words is a compile error. The short
+/// form `uint!("...")` infers `N` from the context and is only checked at
+/// compile time when used in a const context.
+///
+/// ```
+/// use rvv_simulator_runtime::{uint, Uint};
+///
+/// const P: Uint<4> = uint!("0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47");
+/// assert_eq!(uint!(4; "0b1010"), Uint::<4>::from(10u64));
+/// assert_eq!(P % uint!(4; "1_000_000_007"), Uint::<4>::from(121_184_165u64));
+/// ```
+///
+/// ```compile_fail
+/// use rvv_simulator_runtime::uint;
+///
+/// // 2^64 doesn't fit in one word
+/// let x = uint!(1; "0x1_0000_0000_0000_0000");
+/// ```
+#[macro_export]
+macro_rules! uint {
+    ($n:expr; $lit:literal) => {{
+        const VALUE: $crate::Uint<$n> = $crate::Uint::<$n>::from_str_const($lit);
+        VALUE
+    }};
+    ($lit:literal) => {
+        $crate::Uint::from_str_const($lit)
+    };
+}
+
+// `panic!` can't be used in a const fn on the pinned toolchain, but an
+// out-of-bounds index still stops const evaluation, and the error points
+// at the offending line.
+macro_rules! const_error {
+    ($msg:literal) => {{
+        #[allow(unconditional_panic, clippy::out_of_bounds_indexing)]
+        let _ = [$msg][1];
+    }};
+}
+
 #[macro_export]
 #[doc(hidden)]
 macro_rules! overflowing {
@@ -379,17 +424,66 @@ impl<const N: usize> Uint<N> {
         Self::NN
     }
 
-    /// Converts a string slice in a given base to an integer. Only supports radixes of 10
-    /// and 16.
+    /// Converts a string slice in a given base to an integer. Supports radixes 2 to 36,
+    /// digits above 9 are `a-z` or `A-Z`. A `0b`, `0o` or `0x` prefix is accepted when it
+    /// matches the radix.
     pub fn from_str_radix(txt: &str, radix: u32) -> Result<Self, crate::FromStrRadixErr> {
+        if !(2..=36).contains(&radix) {
+            return Err(crate::FromStrRadixErr::unsupported());
+        }
+        let digits = match (radix, Self::radix_prefix(txt)) {
+            (2, Some(2)) | (8, Some(8)) | (16, Some(16)) => &txt[2..],
+            _ => txt,
+        };
         let parsed = match radix {
-            10 => Self::from_dec_str(txt)?,
-            16 => core::str::FromStr::from_str(txt)?,
-            _ => return Err(crate::FromStrRadixErr::unsupported()),
+            10 => Self::from_dec_str(digits)?,
+            16 => core::str::FromStr::from_str(digits)?,
+            _ => Self::from_radix_digits(digits, radix)?,
         };
         Ok(parsed)
     }
 
+    /// Converts a string slice to an integer, picking the radix from its prefix: `0b` for
+    /// binary, `0o` for octal, `0x` for hex, and decimal otherwise.
+    pub fn from_str_prefixed(txt: &str) -> Result<Self, crate::FromStrRadixErr> {
+        match Self::radix_prefix(txt) {
+            Some(radix) => Self::from_str_radix(txt, radix),
+            None => Self::from_str_radix(txt, 10),
+        }
+    }
+
+    fn radix_prefix(txt: &str) -> Option<u32> {
+        match txt.as_bytes() {
+            [b'0', b'b', ..] | [b'0', b'B', ..] => Some(2),
+            [b'0', b'o', ..] | [b'0', b'O', ..] => Some(8),
+            [b'0', b'x', ..] | [b'0', b'X', ..] => Some(16),
+            _ => None,
+        }
+    }
+
+    fn from_radix_digits(digits: &str, radix: u32) -> Result<Self, crate::FromStrRadixErr> {
+        if digits.is_empty() {
+            return Err(crate::FromStrRadixErr::new(
+                crate::FromStrRadixErrKind::InvalidLength,
+            ));
+        }
+        let mut res = Self::zero();
+        for c in digits.chars() {
+            let d = c.to_digit(radix).ok_or_else(|| {
+                crate::FromStrRadixErr::new(crate::FromStrRadixErrKind::InvalidCharacter)
+            })?;
+            let (r, overflow) = res.overflowing_mul_u64(radix as u64);
+            let (r, carry) = r.overflowing_add(Self::from(d as u64));
+            if overflow > 0 || carry {
+                return Err(crate::FromStrRadixErr::new(
+                    crate::FromStrRadixErrKind::InvalidLength,
+                ));
+            }
+            res = r;
+        }
+        Ok(res)
+    }
+
     /// Convert from a decimal string.
     pub fn from_dec_str(value: &str) -> crate::core_::result::Result<Self, crate::FromDecStrErr> {
         let mut res = Self::default();
@@ -410,6 +504,55 @@ impl<const N: usize> Uint<N> {
         Ok(res)
     }
 
+    /// Parses a hex (`0x`), binary (`0b`) or decimal string, ignoring `_` separators.
+    /// Usable in const contexts; see the [`uint!`] macro.
+    ///
+    /// # Panics
+    ///
+    /// Panics (or fails const evaluation) on an empty literal, an invalid digit, or a
+    /// value that doesn't fit in `N` words.
+    pub const fn from_str_const(s: &str) -> Self {
+        let bytes = s.as_bytes();
+        let (radix, mut i) = match bytes {
+            [b'0', b'x', ..] | [b'0', b'X', ..] => (16, 2),
+            [b'0', b'b', ..] | [b'0', b'B', ..] => (2, 2),
+            _ => (10, 0),
+        };
+        if i == bytes.len() {
+            const_error!("empty Uint literal");
+        }
+        let mut ret = [0u64; N];
+        while i < bytes.len() {
+            let c = bytes[i];
+            i += 1;
+            if c == b'_' {
+                continue;
+            }
+            let digit = match c {
+                b'0'..=b'9' => c - b'0',
+                b'a'..=b'f' => c - b'a' + 10,
+                b'A'..=b'F' => c - b'A' + 10,
+                _ => 255,
+            };
+            if digit as u64 >= radix {
+                const_error!("invalid digit in Uint literal");
+            }
+            // ret = ret * radix + digit
+            let mut carry = digit as u64;
+            let mut j = 0;
+            while j < N {
+                let v = ret[j] as u128 * radix as u128 + carry as u128;
+                ret[j] = v as u64;
+                carry = (v >> 64) as u64;
+                j += 1;
+            }
+            if carry != 0 {
+                const_error!("Uint literal is too large for the type");
+            }
+        }
+        Self(ret)
+    }
+
     /// Conversion to u32
     #[inline]
     pub const fn low_u32(&self) -> u32 {
@@ -617,31 +760,23 @@ impl<const N: usize> Uint<N> {
         (Self(u), u_hi.0[N - 1])
     }
 
-    fn full_shr(u: Vec<u64>, shift: u32) -> Self {
+    // Inverse of `full_shl`, with `u_hi` as word `N` of `u`
+    fn full_shr(u: Self, u_hi: u64, shift: u32) -> Self {
         debug_assert!(shift < Self::WORD_BITS as u32);
         let mut res = Self::zero();
         for i in 0..N {
-            res.0[i] = u[i] >> shift;
+            res.0[i] = u.0[i] >> shift;
         }
         // carry
         if shift > 0 {
             for i in 1..=N {
-                res.0[i - 1] |= u[i] << (Self::WORD_BITS as u32 - shift);
+                let w = if i < N { u.0[i] } else { u_hi };
+                res.0[i - 1] |= w << (Self::WORD_BITS as u32 - shift);
             }
         }
         res
     }
 
-    fn full_mul_u64(self, by: u64) -> Vec<u64> {
-        let (prod, carry) = self.overflowing_mul_u64(by);
-        let mut res = Vec::<u64>::new();
-        res.resize(N + 1, 0);
-
-        res[..N].copy_from_slice(&prod.0[..]);
-        res[N] = carry;
-        res
-    }
-
     fn div_mod_small(mut self, other: u64) -> (Self, Self) {
         let mut rem = 0u64;
         self.0.iter_mut().rev().for_each(|d| {
@@ -661,13 +796,8 @@ impl<const N: usize> Uint<N> {
         // and the remainder will only need to be shifted back.
         let shift = v.0[n - 1].leading_zeros();
         v <<= shift;
-        // u will store the remainder (shifted)
-        let (u0, u_high) = self.full_shl(shift);
-        let mut u = Vec::<u64>::new();
-        u.resize(N + 1, 0);
-
-        u[0..N].copy_from_slice(&u0.0[0..N]);
-        u[N] = u_high;
+        // u will store the remainder (shifted), with `u_hi` as word `N`
+        let (Self(mut u), mut u_hi) = self.full_shl(shift);
 
         // quotient
         let mut q = Self::zero();
@@ -677,7 +807,7 @@ impl<const N: usize> Uint<N> {
         // D2. D7.
         // iterate from m downto 0
         for j in (0..=m).rev() {
-            let u_jn = u[j + n];
+            let u_jn = if j + n < N { u[j + n] } else { u_hi };
 
             // D3.
             // q_hat is our guess for the j-th quotient digit
@@ -714,10 +844,13 @@ impl<const N: usize> Uint<N> {
 
             // D4.
             // let's assume optimistically q_hat == q_j
-            // subtract (q_hat * v) from u[j..]
-            let q_hat_v = v.full_mul_u64(q_hat);
-            // u[j..] -= q_hat_v;
-            let c = Self::sub_slice(&mut u[j..], &q_hat_v[..n + 1]);
+            // subtract (q_hat * v) from u[j..=j + n]
+            let (q_hat_v, q_hat_v_hi) = v.overflowing_mul_u64(q_hat);
+            let c = Self::sub_slice(&mut u[j..j + n], &q_hat_v.0[..n]);
+            let x = if n < N { q_hat_v.0[n] } else { q_hat_v_hi };
+            let w = if j + n < N { &mut u[j + n] } else { &mut u_hi };
+            let (res, c) = Self::binop_carry(*w, x, c, u64::overflowing_sub);
+            *w = res;
 
             // D6.
             // actually, q_hat == q_j + 1 and u[j..] has overflowed
@@ -726,7 +859,8 @@ impl<const N: usize> Uint<N> {
                 q_hat -= 1;
                 // add v to u[j..]
                 let c = Self::add_slice(&mut u[j..], &v.0[..n]);
-                u[j + n] = u[j + n].wrapping_add(u64::from(c));
+                let w = if j + n < N { &mut u[j + n] } else { &mut u_hi };
+                *w = w.wrapping_add(u64::from(c));
             }
 
             // D5.
@@ -734,7 +868,7 @@ impl<const N: usize> Uint<N> {
         }
 
         // D8.
-        let remainder = Self::full_shr(u, shift);
+        let remainder = Self::full_shr(Self(u), u_hi, shift);
 
         (q, remainder)
     }
@@ -966,69 +1100,32 @@ impl<const N: usize> Uint<N> {
         }
     }
 
-    pub fn uint_full_mul_reg(self, other: Self) -> Vec<u64> {
-        let Self(ref me) = self;
-        let Self(ref you) = other;
-        let mut ret = Vec::<u64>::new();
-        ret.resize(N * 2, 0);
-
-        for i in 0..N {
-            let mut carry = 0u64;
-            let b = you[i];
-            for j in 0..N {
-                // if $check(me[j], carry) {
-                if true {
-                    let a = me[j];
-
-                    let (hi, low) = Self::split_u128(a as u128 * b as u128);
-
-                    let overflow = {
-                        let existing_low = &mut ret[i + j];
-                        let (low, o) = low.overflowing_add(*existing_low);
-                        *existing_low = low;
-                        o
-                    };
-
-                    carry = {
-                        let existing_hi = &mut ret[i + j + 1];
-                        let hi = hi + overflow as u64;
-                        let (hi, o0) = hi.overflowing_add(carry);
-                        let (hi, o1) = hi.overflowing_add(*existing_hi);
-                        *existing_hi = hi;
-
-                        (o0 | o1) as u64
-                    }
-                }
-            }
-        }
+    /// Full product of `self` and `other` as `2 * N` little-endian words.
+    #[cfg(feature = "alloc")]
+    pub fn uint_full_mul_reg(self, other: Self) -> alloc::vec::Vec<u64> {
+        let (lo, hi) = self.widening_mul(other);
+        let mut ret = alloc::vec::Vec::<u64>::new();
+        ret.extend_from_slice(&lo.0);
+        ret.extend_from_slice(&hi.0);
         ret
     }
+
+    /// Full double-width product of `self` and `other`, returned as
+    /// `(low, high)` halves. Doesn't allocate.
+    ///
+    /// Uses schoolbook multiplication up to 8 words and for odd sizes, and
+    /// Karatsuba for even sizes above that. Doesn't branch on the operands.
+    #[inline]
+    pub fn widening_mul(self, other: Self) -> (Self, Self) {
+        let (lo, hi) = crate::mul::widening_mul(&self.0, &other.0);
+        (Self(lo), Self(hi))
+    }
+
     /// Multiply with overflow, returning a flag if it does.
     #[inline(always)]
     pub fn overflowing_mul(self, other: Self) -> (Self, bool) {
-        let ret = self.uint_full_mul_reg(other);
-
-        // The safety of this is enforced by the compiler
-        // let ret: [[u64; N]; 2] = unsafe { crate::core_::mem::transmute(ret.as_ptr()) };
-        let mut ret0 = [0u64; N];
-        let mut ret1 = [0u64; N];
-        ret0.copy_from_slice(&ret[0..N]);
-        ret1.copy_from_slice(&ret[N..2 * N]);
-
-        let ret0 = Self(ret0);
-        let ret1 = Self(ret1);
-
-        // The compiler WILL NOT inline this if you remove this annotation.
-        #[inline(always)]
-        fn any_nonzero<const N: usize>(arr: &[u64; N]) -> bool {
-            for i in 0..N {
-                if arr[i] != 0 {
-                    return true;
-                }
-            }
-            false
-        }
-        (ret0, any_nonzero::<N>(&ret1.0))
+        let (lo, hi) = self.widening_mul(other);
+        (lo, !hi.is_zero())
     }
 
     /// Multiplication which saturates at the maximum value..
@@ -1070,7 +1167,7 @@ impl<const N: usize> Uint<N> {
         if self.is_zero() {
             (self, false)
         } else {
-            (!self, true)
+            (!self + Self::one(), true)
         }
     }
 
@@ -1093,10 +1190,10 @@ impl<const N: usize> Uint<N> {
         const TWO32: u64 = 1 << 32;
         let s = y.leading_zeros();
         let y = y << s;
-        let (yn1, yn0) = Self::split(y);
+        let (yn1, yn0) = Self::split_u64(y);
         let un32 = (hi << s) | lo.checked_shr(64 - s).unwrap_or(0);
         let un10 = lo << s;
-        let (un1, un0) = Self::split(un10);
+        let (un1, un0) = Self::split_u64(un10);
         let mut q1 = un32 / yn1;
         let mut rhat = un32 - q1 * yn1;
 
@@ -1174,7 +1271,7 @@ impl<const N: usize> Uint<N> {
     }
 
     #[inline(always)]
-    const fn split(a: u64) -> (u64, u64) {
+    const fn split_u64(a: u64) -> (u64, u64) {
         (a >> 32, a & 0xFFFF_FFFF)
     }
 
@@ -1206,14 +1303,9 @@ impl<const N: usize> Uint<N> {
             slice
         };
 
-        let mut padded = Vec::<u8>::new();
-        padded.resize(N * 8, 0);
-
-        padded[N * 8 - slice.len()..N * 8].copy_from_slice(slice);
-
-        let mut ret = [0; N];
-        for i in 0..N {
-            ret[N - i - 1] = BigEndian::read_u64(&padded[8 * i..]);
+        let mut ret = [0u64; N];
+        for (i, &b) in slice.iter().rev().enumerate() {
+            ret[i / 8] |= (b as u64) << (i % 8 * 8);
         }
         Self(ret)
     }
@@ -1222,14 +1314,9 @@ impl<const N: usize> Uint<N> {
     pub fn from_little_endian(slice: &[u8]) -> Self {
         assert!(N * 8 >= slice.len());
 
-        let mut padded = Vec::<u8>::new();
-        padded.resize(N * 8, 0);
-
-        padded[0..slice.len()].copy_from_slice(slice);
-
-        let mut ret = [0; N];
-        for i in 0..N {
-            ret[i] = LittleEndian::read_u64(&padded[8 * i..]);
+        let mut ret = [0u64; N];
+        for (i, &b) in slice.iter().enumerate() {
+            ret[i / 8] |= (b as u64) << (i % 8 * 8);
         }
         Self(ret)
     }
@@ -1380,41 +1467,25 @@ impl<const N: usize> crate::core_::str::FromStr for Uint<N> {
     type Err = crate::FromHexError;
     fn from_str(value: &str) -> crate::core_::result::Result<Uint<N>, Self::Err> {
         let value = value.strip_prefix("0x").unwrap_or(value);
-        // can't use `N+1`: use maximum value instead
-        // const BYTES_LEN: usize = $n_words * 8;
-        const BYTES_LEN: usize = 64 * 8;
-        const MAX_ENCODED_LEN: usize = BYTES_LEN * 2;
-
-        let mut bytes = [0_u8; BYTES_LEN];
-
-        let encoded = value.as_bytes();
-
-        if encoded.len() % 2 == 0 {
-            let out = &mut bytes[BYTES_LEN - encoded.len() / 2..];
-
-            crate::hex::decode_to_slice(encoded, out).map_err(Self::Err::from)?;
-        } else {
-            // Prepend '0' by overlaying our value on a scratch buffer filled with '0' characters.
-            let mut s = [b'0'; MAX_ENCODED_LEN];
-            s[MAX_ENCODED_LEN - encoded.len()..].copy_from_slice(encoded);
-            let encoded = &s[MAX_ENCODED_LEN - encoded.len() - 1..];
-
-            let out = &mut bytes[BYTES_LEN - encoded.len() / 2..];
-
-            crate::hex::decode_to_slice(encoded, out).map_err(Self::Err::from)?;
+        let digits = value.as_bytes();
+        if digits.len() > N * 16 {
+            return Err(FromHexError {
+                inner: HexErrorKind::InvalidLength,
+            });
         }
 
-        let bytes_ref: &[u8] = &bytes;
-        let res: Uint<N> = From::from(bytes_ref);
-
-        let n = res.get_n();
-        let bytes_len: usize = n * 8;
-        let max_encoded_len: usize = bytes_len * 2;
-        if encoded.len() > max_encoded_len {
-            return Err(crate::hex::FromHexError::InvalidStringLength.into());
+        if let Some((index, c)) = value.char_indices().find(|(_, c)| !c.is_ascii_hexdigit()) {
+            return Err(FromHexError {
+                inner: HexErrorKind::InvalidCharacter { c, index },
+            });
         }
 
-        Ok(res)
+        let mut ret = [0u64; N];
+        for (i, &d) in digits.iter().rev().enumerate() {
+            let nibble = (d as char).to_digit(16).unwrap() as u64;
+            ret[i / 16] |= nibble << (i % 16 * 4);
+        }
+        Ok(Uint(ret))
     }
 }
 
@@ -1722,95 +1793,338 @@ impl<const N: usize> crate::core_::fmt::Debug for Uint<N> {
 
 impl<const N: usize> crate::core_::fmt::Display for Uint<N> {
     fn fmt(&self, f: &mut crate::core_::fmt::Formatter) -> crate::core_::fmt::Result {
-        if self.is_zero() {
-            return crate::core_::write!(f, "0");
-        }
-
-        // let mut buf = [0_u8; N * 20];
-        let mut buf = Vec::<u8>::new();
-        buf.resize(N * 20, 0);
+        self.fmt_radix(f, 10, false, "")
+    }
+}
 
-        let mut i = buf.len() - 1;
-        let mut current = *self;
-        let ten = Self::from(10);
+impl<const N: usize> crate::core_::fmt::LowerHex for Uint<N> {
+    fn fmt(&self, f: &mut crate::core_::fmt::Formatter) -> crate::core_::fmt::Result {
+        self.fmt_radix(f, 16, false, "0x")
+    }
+}
 
-        loop {
-            let digit = (current % ten).low_u64() as u8;
-            buf[i] = digit + b'0';
-            current /= ten;
-            if current.is_zero() {
-                break;
-            }
-            i -= 1;
-        }
+impl<const N: usize> crate::core_::fmt::UpperHex for Uint<N> {
+    fn fmt(&self, f: &mut crate::core_::fmt::Formatter) -> crate::core_::fmt::Result {
+        self.fmt_radix(f, 16, true, "0x")
+    }
+}
 
-        // sequence of `'0'..'9'` chars is guaranteed to be a valid UTF8 string
-        let s = unsafe { crate::core_::str::from_utf8_unchecked(&buf[i..]) };
-        f.write_str(s)
+impl<const N: usize> crate::core_::fmt::Octal for Uint<N> {
+    fn fmt(&self, f: &mut crate::core_::fmt::Formatter) -> crate::core_::fmt::Result {
+        self.fmt_radix(f, 8, false, "0o")
     }
 }
 
-impl<const N: usize> crate::core_::fmt::LowerHex for Uint<N> {
+impl<const N: usize> crate::core_::fmt::Binary for Uint<N> {
     fn fmt(&self, f: &mut crate::core_::fmt::Formatter) -> crate::core_::fmt::Result {
-        let &Self(ref data) = self;
-        if f.alternate() {
-            crate::core_::write!(f, "0x")?;
+        self.fmt_radix(f, 2, false, "0b")
+    }
+}
+
+impl<const N: usize> Uint<N> {
+    /// Converts to a string of digits in the given radix (2 to 36), using lowercase
+    /// letters for digits above 9 and no prefix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is out of range.
+    #[cfg(feature = "alloc")]
+    pub fn to_str_radix(&self, radix: u32) -> alloc::string::String {
+        assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+        let mut s = alloc::string::String::new();
+        // Writing to a `String` can't fail
+        let _ = self.write_radix(&mut s, radix, false);
+        s
+    }
+
+    // Largest power of `radix` that fits in a word, and its exponent.
+    fn radix_chunk(radix: u32) -> (u64, usize) {
+        let radix = radix as u64;
+        let mut chunk = radix;
+        let mut digits = 1;
+        while let Some(next) = chunk.checked_mul(radix) {
+            chunk = next;
+            digits += 1;
+        }
+        (chunk, digits)
+    }
+
+    fn num_digits(&self, radix: u32) -> usize {
+        let (chunk, chunk_digits) = Self::radix_chunk(radix);
+        let mut current = *self;
+        let mut digits = 0;
+        while !current.fits_word() || current.0[0] >= chunk {
+            current = current.div_mod_small(chunk).0;
+            digits += chunk_digits;
         }
-        // special case.
-        if self.is_zero() {
-            return crate::core_::write!(f, "0");
+        let mut low = current.0[0];
+        loop {
+            digits += 1;
+            low /= radix as u64;
+            if low == 0 {
+                break digits;
+            }
         }
+    }
 
-        let mut latch = false;
-        for ch in data.iter().rev() {
-            for x in 0..16 {
-                let nibble = (ch & (15u64 << ((15 - x) * 4) as u64)) >> (((15 - x) * 4) as u64);
-                if !latch {
-                    latch = nibble != 0;
-                }
+    // Writes the digits without any buffer proportional to N: split off the
+    // lowest word-sized chunk of digits, write the rest first, then the
+    // chunk zero-padded.
+    fn write_radix<W: crate::core_::fmt::Write>(
+        &self,
+        w: &mut W,
+        radix: u32,
+        upper: bool,
+    ) -> crate::core_::fmt::Result {
+        let (chunk, chunk_digits) = Self::radix_chunk(radix);
+        let (rest, low) = self.div_mod_small(chunk);
+        if rest.is_zero() {
+            Self::write_word(w, low.0[0], radix, upper, 1)
+        } else {
+            rest.write_radix(w, radix, upper)?;
+            Self::write_word(w, low.0[0], radix, upper, chunk_digits)
+        }
+    }
 
-                if latch {
-                    crate::core_::write!(f, "{:x}", nibble)?;
-                }
+    fn write_word<W: crate::core_::fmt::Write>(
+        w: &mut W,
+        mut word: u64,
+        radix: u32,
+        upper: bool,
+        min_digits: usize,
+    ) -> crate::core_::fmt::Result {
+        let mut buf = [0u8; 64];
+        let mut i = buf.len();
+        while word != 0 || buf.len() - i < min_digits {
+            i -= 1;
+            let digit = (word % radix as u64) as u8;
+            buf[i] = match digit {
+                0..=9 => b'0' + digit,
+                _ if upper => b'A' + digit - 10,
+                _ => b'a' + digit - 10,
+            };
+            word /= radix as u64;
+        }
+        // only ASCII digits and letters were written
+        let s = unsafe { crate::core_::str::from_utf8_unchecked(&buf[i..]) };
+        w.write_str(s)
+    }
+
+    fn fmt_radix(
+        &self,
+        f: &mut crate::core_::fmt::Formatter,
+        radix: u32,
+        upper: bool,
+        prefix: &str,
+    ) -> crate::core_::fmt::Result {
+        self.fmt_radix_signed(f, false, radix, upper, prefix)
+    }
+
+    // Same flag handling as `Formatter::pad_integral`, which needs the whole
+    // digit string up front. `self` is the magnitude.
+    // `div_ceil` isn't available on the pinned toolchain
+    #[allow(unknown_lints, clippy::manual_div_ceil)]
+    pub(crate) fn fmt_radix_signed(
+        &self,
+        f: &mut crate::core_::fmt::Formatter,
+        negative: bool,
+        radix: u32,
+        upper: bool,
+        prefix: &str,
+    ) -> crate::core_::fmt::Result {
+        use crate::core_::fmt::{Alignment, Write};
+
+        let sign = if negative {
+            "-"
+        } else if f.sign_plus() {
+            "+"
+        } else {
+            ""
+        };
+        let prefix = if f.alternate() { prefix } else { "" };
+        let len = sign.len() + prefix.len() + self.num_digits(radix);
+        let padding = match f.width() {
+            Some(width) if width > len => width - len,
+            _ => 0,
+        };
+
+        if f.sign_aware_zero_pad() {
+            f.write_str(sign)?;
+            f.write_str(prefix)?;
+            for _ in 0..padding {
+                f.write_char('0')?;
             }
+            return self.write_radix(f, radix, upper);
+        }
+
+        let (pre, post) = match f.align() {
+            Some(Alignment::Left) => (0, padding),
+            Some(Alignment::Center) => (padding / 2, (padding + 1) / 2),
+            Some(Alignment::Right) | None => (padding, 0),
+        };
+        let fill = f.fill();
+        for _ in 0..pre {
+            f.write_char(fill)?;
+        }
+        f.write_str(sign)?;
+        f.write_str(prefix)?;
+        self.write_radix(f, radix, upper)?;
+        for _ in 0..post {
+            f.write_char(fill)?;
         }
         Ok(())
     }
 }
 
 // The following APIs are not in original uint version
+impl<const N: usize> Uint<N> {
+    /// Converts to `Uint<M>`, zero-extending if `M > N` and keeping the low
+    /// `M` words if `M < N`.
+    pub fn resize<const M: usize>(self) -> Uint<M> {
+        let mut arr = [0u64; M];
+        let len = if M < N { M } else { N };
+        arr[..len].copy_from_slice(&self.0[..len]);
+        Uint::<M>(arr)
+    }
+
+    /// Converts to `Uint<M>`. Returns `None` if the value doesn't fit.
+    pub fn checked_resize<const M: usize>(self) -> Option<Uint<M>> {
+        if M < N && self.0[M..].iter().any(|&w| w != 0) {
+            return None;
+        }
+        Some(self.resize())
+    }
+
+    /// Adds `other` of any width. Returns the sum wrapped to `N` words and
+    /// whether the exact sum doesn't fit in `N` words.
+    pub fn overflowing_add_mixed<const M: usize>(self, other: Uint<M>) -> (Self, bool) {
+        let (sum, carry) = self.overflowing_add(other.resize());
+        (
+            sum,
+            carry || (M > N && other.0[N..].iter().any(|&w| w != 0)),
+        )
+    }
+}
+
+// Lossless `From` from every size to every larger one
 macro_rules! convert {
-    ($small:tt, $big:tt) => {
-        impl crate::core_::convert::From<Uint<$small>> for Uint<$big> {
-            fn from(num: Uint<$small>) -> Self {
-                let Uint::<$small>(ref arr) = num;
-                let mut arr2 = [0; $big];
-                for i in 0..$small {
-                    arr2[i] = arr[i];
+    ($small:tt => $($big:tt)*) => {
+        $(
+            impl crate::core_::convert::From<Uint<$small>> for Uint<$big> {
+                fn from(num: Uint<$small>) -> Self {
+                    num.resize()
                 }
-                Uint::<$big>(arr2)
             }
-        }
+        )*
+    };
+}
+
+convert!(1 => 2 4 8 16 32 64);
+convert!(2 => 4 8 16 32 64);
+convert!(4 => 8 16 32 64);
+convert!(8 => 16 32 64);
+convert!(16 => 32 64);
+convert!(32 => 64);
 
+// The `rvv_vector` examples rely on these narrowing conversions, which keep
+// the low half like `as` does. Other sizes narrow with `resize` or
+// `checked_resize`.
+macro_rules! truncate {
+    ($big:tt, $small:tt) => {
         impl crate::core_::convert::From<Uint<$big>> for Uint<$small> {
             fn from(num: Uint<$big>) -> Self {
-                let Uint::<$big>(ref arr) = num;
-                let mut arr2 = [0; $small];
-                for i in 0..$small {
-                    arr2[i] = arr[i];
+                num.resize()
+            }
+        }
+    };
+}
+
+truncate!(8, 4);
+truncate!(16, 8);
+truncate!(32, 16);
+
+// Operations between a type and the one twice as wide
+macro_rules! double_width {
+    ($small:tt, $big:tt) => {
+        impl Uint<$small> {
+            /// Full product of `self` and `other` as the double-width type.
+            pub fn full_mul(self, other: Self) -> Uint<$big> {
+                let (lo, hi) = self.widening_mul(other);
+                Self::concat(hi, lo)
+            }
+
+            /// Joins two halves into the double-width value `hi * 2^(64 * N) + lo`.
+            pub fn concat(hi: Self, lo: Self) -> Uint<$big> {
+                let mut arr = [0; $big];
+                arr[..$small].copy_from_slice(&lo.0);
+                arr[$small..].copy_from_slice(&hi.0);
+                Uint::<$big>(arr)
+            }
+        }
+
+        impl Uint<$big> {
+            /// Splits into the high and low halves, the inverse of `concat`.
+            pub fn split(self) -> (Uint<$small>, Uint<$small>) {
+                let mut hi = [0; $small];
+                let mut lo = [0; $small];
+                lo.copy_from_slice(&self.0[..$small]);
+                hi.copy_from_slice(&self.0[$small..]);
+                (Uint::<$small>(hi), Uint::<$small>(lo))
+            }
+        }
+    };
+}
+
+double_width!(1, 2);
+double_width!(2, 4);
+double_width!(4, 8);
+double_width!(8, 16);
+double_width!(16, 32);
+double_width!(32, 64);
+
+macro_rules! byte_array {
+    ($n:tt, $bytes:tt) => {
+        impl Uint<$n> {
+            /// Big-endian bytes.
+            pub fn to_be_bytes(&self) -> [u8; $bytes] {
+                let mut arr = [0u8; $bytes];
+                self.to_big_endian(&mut arr);
+                arr
+            }
+
+            /// Little-endian bytes.
+            pub fn to_le_bytes(&self) -> [u8; $bytes] {
+                let mut arr = [0u8; $bytes];
+                self.to_little_endian(&mut arr);
+                arr
+            }
+
+            pub fn from_be_bytes(bytes: [u8; $bytes]) -> Self {
+                let mut ret = [0u64; $n];
+                for i in 0..$n {
+                    ret[$n - i - 1] = BigEndian::read_u64(&bytes[8 * i..]);
                 }
-                Uint::<$small>(arr2)
+                Uint::<$n>(ret)
+            }
+
+            pub fn from_le_bytes(bytes: [u8; $bytes]) -> Self {
+                let mut ret = [0u64; $n];
+                for i in 0..$n {
+                    ret[i] = LittleEndian::read_u64(&bytes[8 * i..]);
+                }
+                Uint::<$n>(ret)
             }
         }
     };
 }
 
-// U256 <-> U512
-convert!(4, 8);
-// U512 <-> U1024
-convert!(8, 16);
-// U1024 <-> U2048
-convert!(16, 32);
+byte_array!(1, 8);
+byte_array!(2, 16);
+byte_array!(4, 32);
+byte_array!(8, 64);
+byte_array!(16, 128);
+byte_array!(32, 256);
+byte_array!(64, 512);
 
 impl<const N: usize> Uint<N> {
     pub fn wrapping_add(self, other: Self) -> Self {
@@ -1825,4 +2139,194 @@ impl<const N: usize> Uint<N> {
         let (res, _) = self.overflowing_mul(other);
         res
     }
+    pub fn wrapping_neg(self) -> Self {
+        let (res, _) = self.overflowing_neg();
+        res
+    }
+    pub fn wrapping_pow(self, expon: Self) -> Self {
+        let (res, _) = self.overflowing_pow(expon);
+        res
+    }
+
+    /// Exponentiation which saturates at the maximum value.
+    pub fn saturating_pow(self, expon: Self) -> Self {
+        match self.overflowing_pow(expon) {
+            (_, true) => Self::max_value(),
+            (val, false) => val,
+        }
+    }
+
+    /// Shift left by `rhs % bit width`, returning whether `rhs` was out of range.
+    pub fn overflowing_shl(self, rhs: u32) -> (Self, bool) {
+        let bits = (N * 64) as u32;
+        (self << (rhs % bits), rhs >= bits)
+    }
+
+    /// Shift right by `rhs % bit width`, returning whether `rhs` was out of range.
+    pub fn overflowing_shr(self, rhs: u32) -> (Self, bool) {
+        let bits = (N * 64) as u32;
+        (self >> (rhs % bits), rhs >= bits)
+    }
+
+    /// Checked shift left. Returns `None` if `rhs` is not less than the bit width.
+    pub fn checked_shl(self, rhs: u32) -> Option<Self> {
+        match self.overflowing_shl(rhs) {
+            (_, true) => None,
+            (val, false) => Some(val),
+        }
+    }
+
+    /// Checked shift right. Returns `None` if `rhs` is not less than the bit width.
+    pub fn checked_shr(self, rhs: u32) -> Option<Self> {
+        match self.overflowing_shr(rhs) {
+            (_, true) => None,
+            (val, false) => Some(val),
+        }
+    }
+
+    pub fn wrapping_shl(self, rhs: u32) -> Self {
+        let (res, _) = self.overflowing_shl(rhs);
+        res
+    }
+    pub fn wrapping_shr(self, rhs: u32) -> Self {
+        let (res, _) = self.overflowing_shr(rhs);
+        res
+    }
+
+    /// Absolute difference `|self - other|`.
+    pub fn abs_diff(self, other: Self) -> Self {
+        if self >= other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+
+    /// Whether `self == 2^k` for some `k`.
+    pub fn is_power_of_two(&self) -> bool {
+        self.count_ones() == 1
+    }
+
+    /// Smallest power of two greater than or equal to `self`. Returns `None`
+    /// if it doesn't fit.
+    pub fn checked_next_power_of_two(self) -> Option<Self> {
+        if self <= Self::one() {
+            return Some(Self::one());
+        }
+        let shift = (self - Self::one()).bits();
+        if shift == N * 64 {
+            None
+        } else {
+            Some(Self::one() << shift)
+        }
+    }
+
+    /// Smallest power of two greater than or equal to `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows.
+    pub fn next_power_of_two(self) -> Self {
+        let res = self.checked_next_power_of_two();
+        panic_on_overflow!(res.is_none());
+        res.unwrap_or_default()
+    }
+
+    /// Sets bit `index` to `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` exceeds the bit width of the number.
+    pub fn set_bit(&mut self, index: usize, value: bool) {
+        let mask = 1u64 << (index % 64);
+        if value {
+            self.0[index / 64] |= mask;
+        } else {
+            self.0[index / 64] &= !mask;
+        }
+    }
+
+    /// Returns the number of ones in the binary representation of self.
+    pub fn count_ones(&self) -> u32 {
+        self.0.iter().map(|w| w.count_ones()).sum()
+    }
+
+    /// Returns the number of zeros in the binary representation of self.
+    pub fn count_zeros(&self) -> u32 {
+        self.0.iter().map(|w| w.count_zeros()).sum()
+    }
+
+    /// Shifts the bits left by `n`, wrapping the truncated bits to the end.
+    pub fn rotate_left(self, n: u32) -> Self {
+        let n = n as usize % (N * 64);
+        if n == 0 {
+            return self;
+        }
+        (self << n) | (self >> (N * 64 - n))
+    }
+
+    /// Shifts the bits right by `n`, wrapping the truncated bits to the beginning.
+    pub fn rotate_right(self, n: u32) -> Self {
+        let n = n as usize % (N * 64);
+        self.rotate_left((N * 64 - n) as u32)
+    }
+
+    /// Reverses the byte order.
+    pub fn swap_bytes(self) -> Self {
+        let mut ret = [0u64; N];
+        for i in 0..N {
+            ret[i] = self.0[N - i - 1].swap_bytes();
+        }
+        Self(ret)
+    }
+
+    /// Reverses the bit order.
+    pub fn reverse_bits(self) -> Self {
+        let mut ret = [0u64; N];
+        for i in 0..N {
+            ret[i] = self.0[N - i - 1].reverse_bits();
+        }
+        Self(ret)
+    }
+
+    /// Iterates over the significant bits (`0..self.bits()`), least significant
+    /// first. Use `.rev()` or [`Uint::iter_bits_msb`] for most significant first.
+    pub fn iter_bits(&self) -> BitIter<N> {
+        BitIter {
+            value: *self,
+            range: 0..self.bits(),
+        }
+    }
+
+    /// Iterates over the significant bits, most significant first.
+    pub fn iter_bits_msb(&self) -> crate::core_::iter::Rev<BitIter<N>> {
+        self.iter_bits().rev()
+    }
 }
+
+/// Iterator over the bits of a `Uint`, see [`Uint::iter_bits`].
+#[derive(Clone, Debug)]
+pub struct BitIter<const N: usize> {
+    value: Uint<N>,
+    range: crate::core_::ops::Range<usize>,
+}
+
+impl<const N: usize> Iterator for BitIter<N> {
+    type Item = bool;
+
+    fn next(&mut self) -> Option<bool> {
+        self.range.next().map(|i| self.value.bit(i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<const N: usize> DoubleEndedIterator for BitIter<N> {
+    fn next_back(&mut self) -> Option<bool> {
+        self.range.next_back().map(|i| self.value.bit(i))
+    }
+}
+
+impl<const N: usize> ExactSizeIterator for BitIter<N> {}
@@ -0,0 +1,140 @@
+mod common;
+
+use common::samples;
+use rvv_simulator_runtime::{uint, Barrett, Uint};
+
+type U64 = Uint<1>;
+type U128 = Uint<2>;
+type U256 = Uint<4>;
+
+const FQ: U256 = uint!("0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47");
+const FR: U256 = uint!("0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001");
+
+fn u256_samples() -> Vec<U256> {
+    let words: Vec<u64> = samples().collect();
+    let mut v: Vec<U256> = words
+        .chunks(4)
+        .map(|w| Uint([w[0], w[1], w[2], w[3]]))
+        .collect();
+    v.extend_from_slice(&[U256::zero(), U256::one(), U256::MAX, FQ - U256::one(), FQ]);
+    v
+}
+
+#[test]
+fn uint128_reduce_matches_u128() {
+    let moduli = [
+        1u128,
+        2,
+        3,
+        1 << 64,
+        (1 << 64) + 1,
+        0xffff_ffff_ffff_ffc5,
+        (1 << 127) - 1,
+        1 << 127,
+        u128::MAX,
+    ];
+    for &m in moduli.iter() {
+        let b = Barrett::new(U128::from(m)).unwrap();
+        for (x, y) in samples().zip(samples().skip(1)) {
+            let x = (x as u128) << 64 | y as u128;
+            assert_eq!(b.reduce(U128::from(x)), U128::from(x % m), "{} % {}", x, m);
+        }
+    }
+}
+
+#[test]
+fn uint64_reduce_wide_matches_u128() {
+    let moduli = [
+        1u64,
+        2,
+        3,
+        5,
+        1 << 32,
+        0xffff_ffff_ffff_ffc5,
+        1 << 63,
+        u64::MAX,
+    ];
+    for &m in moduli.iter() {
+        let b = Barrett::new(U64::from(m)).unwrap();
+        for (lo, hi) in samples().zip(samples().skip(1)) {
+            let x = (hi as u128) << 64 | lo as u128;
+            assert_eq!(
+                b.reduce_wide(U64::from(lo), U64::from(hi)),
+                U64::from((x % m as u128) as u64),
+                "{} % {}",
+                x,
+                m
+            );
+        }
+    }
+}
+
+#[test]
+fn uint256_reduce_wide_small_modulus() {
+    // Moduli a few bits wide still reduce a 512-bit value in two steps
+    let xs = u256_samples();
+    for &m in [1u64, 2, 3, 7, 97, 0xffff_ffff_ffff_ffc5].iter() {
+        let m = U256::from(m);
+        let b = Barrett::new(m).unwrap();
+        // 2^512 - 1 == MAX * 2^256 + MAX
+        let r = U256::MAX % m;
+        let expected = (r.mul_mod(r + U256::one(), m) + r) % m;
+        assert_eq!(b.reduce_wide(U256::MAX, U256::MAX), expected);
+        for (x, y) in xs.iter().zip(xs.iter().rev()) {
+            assert_eq!(b.mul_mod(*x, *y), x.mul_mod(*y, m), "{} * {} % {}", x, y, m);
+            assert_eq!(b.reduce(*x), *x % m);
+        }
+    }
+}
+
+#[test]
+fn uint256_reduce_wide_matches_mul_mod() {
+    let moduli = [
+        FQ,
+        FR,
+        U256::from(97u64),
+        U256::from(1u64) << 200,
+        U256::from(1u64) << 255,
+        U256::MAX,
+        U256::MAX - U256::from(188u64),
+        (U256::from(1u64) << 192) + U256::one(),
+    ];
+    let xs = u256_samples();
+    for &m in moduli.iter() {
+        let b = Barrett::new(m).unwrap();
+        assert_eq!(b.modulus(), m);
+        assert_eq!(b.bits(), m.bits());
+        for (x, y) in xs.iter().zip(xs.iter().rev()) {
+            assert_eq!(b.mul_mod(*x, *y), x.mul_mod(*y, m), "{} * {} % {}", x, y, m);
+            assert_eq!(b.reduce(*x), *x % m);
+            assert_eq!(b.reduce_full(x.full_mul(*y)), x.mul_mod(*y, m));
+        }
+    }
+}
+
+#[test]
+fn uint256_barrett_mu() {
+    // floor(2^(256 + n) / m) - 2^256 for a few moduli where it's easy to check
+    let b = Barrett::new(U256::from(3u64)).unwrap();
+    assert_eq!(b.bits(), 2);
+    assert_eq!(b.mu(), U256::MAX / U256::from(3u64));
+    let b = Barrett::new(U256::from(5u64) << 100).unwrap();
+    assert_eq!(b.bits(), 103);
+    assert_eq!(b.mu(), (U256::MAX / U256::from(5u64)) * U256::from(3u64));
+    let b = Barrett::new(U256::from(1u64) << 100).unwrap();
+    assert_eq!(b.mu(), U256::MAX);
+    let b = Barrett::new(U256::from(1u64) << 255).unwrap();
+    assert_eq!(b.mu(), U256::MAX);
+    assert!(Barrett::new(U256::zero()).is_none());
+}
+
+#[test]
+fn uint256_reduce_slice() {
+    let b = Barrett::new(FR).unwrap();
+    let xs = u256_samples();
+    let mut out = vec![U256::zero(); xs.len()];
+    b.reduce_slice(&xs, &mut out);
+    for (x, o) in xs.iter().zip(&out) {
+        assert_eq!(*o, *x % FR);
+    }
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use core::cmp::Ordering;
+use rvv_simulator_runtime::batch;
+use rvv_simulator_runtime::{uint, Uint};
+
+type U256 = Uint<4>;
+
+const FQ: U256 = uint!("0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47");
+
+fn values(seed: u64, len: usize) -> Vec<U256> {
+    let mut v: Vec<U256> = common::uints(seed)
+        .take(len)
+        .map(|x: U256| x >> 2)
+        .collect();
+    v[0] = U256::MAX;
+    v[1] = U256::zero();
+    v
+}
+
+#[test]
+fn uint256_arith_slices_match_scalar() {
+    let a = values(common::SEED, 70);
+    let b = values(0x1234_5678_9abc_def1, 70);
+    let mut out = vec![U256::zero(); a.len()];
+
+    batch::add_slice(&a, &b, &mut out);
+    for i in 0..a.len() {
+        assert_eq!(out[i], a[i].wrapping_add(b[i]));
+    }
+    batch::sub_slice(&a, &b, &mut out);
+    for i in 0..a.len() {
+        assert_eq!(out[i], a[i].wrapping_sub(b[i]));
+    }
+    batch::mul_slice(&a, &b, &mut out);
+    for i in 0..a.len() {
+        assert_eq!(out[i], a[i].wrapping_mul(b[i]));
+    }
+
+    let mut c = a.clone();
+    batch::add_assign_slice(&mut c, &b);
+    batch::sub_assign_slice(&mut c, &b);
+    assert_eq!(c, a);
+    batch::mul_assign_slice(&mut c, &b);
+    batch::mul_slice(&a, &b, &mut out);
+    assert_eq!(c, out);
+
+    let mut hi = vec![U256::zero(); a.len()];
+    batch::widening_mul_slice(&a, &b, &mut out, &mut hi);
+    for i in 0..a.len() {
+        assert_eq!((out[i], hi[i]), a[i].widening_mul(b[i]));
+    }
+}
+
+#[test]
+fn uint256_mod_slices_match_scalar() {
+    let a = values(common::SEED, 20);
+    let b = values(0x1234_5678_9abc_def1, 20);
+    let mut out = vec![U256::zero(); a.len()];
+    batch::add_mod_slice(&a, &b, FQ, &mut out);
+    for i in 0..a.len() {
+        assert_eq!(out[i], a[i].add_mod(b[i], FQ));
+    }
+    batch::sub_mod_slice(&a, &b, FQ, &mut out);
+    for i in 0..a.len() {
+        assert_eq!(out[i], a[i].sub_mod(b[i], FQ));
+    }
+    batch::mul_mod_slice(&a, &b, FQ, &mut out);
+    for i in 0..a.len() {
+        assert_eq!(out[i], a[i].mul_mod(b[i], FQ));
+    }
+}
+
+#[test]
+fn uint256_cmp_and_select_slices() {
+    let a = values(common::SEED, 130);
+    let mut b = values(0x1234_5678_9abc_def1, 130);
+    b[5] = a[5];
+    b[100] = a[100];
+    assert_eq!(batch::mask_len(a.len()), 3);
+    // stale bits get cleared
+    let mut mask = [u64::MAX; 3];
+
+    batch::cmp_slice(&a, &b, Ordering::Equal, &mut mask);
+    // both sample sets start with MAX and 0
+    assert_eq!(mask, [1 << 5 | 0b11, 1 << (100 - 64), 0]);
+
+    batch::cmp_slice(&a, &b, Ordering::Less, &mut mask);
+    for i in 0..a.len() {
+        assert_eq!(batch::mask_bit(&mask, i), a[i] < b[i]);
+    }
+    assert_eq!(mask[2] >> 2, 0);
+
+    let mut out = vec![U256::zero(); a.len()];
+    batch::select_slice(&mask, &a, &b, &mut out);
+    for i in 0..a.len() {
+        assert_eq!(out[i], a[i].max(b[i]));
+    }
+}
+
+#[test]
+#[should_panic]
+fn uint256_slices_length_mismatch() {
+    let a = values(1, 4);
+    let b = values(2, 3);
+    let mut out = vec![U256::zero(); 4];
+    batch::add_slice(&a, &b, &mut out);
+}
+
+#[test]
+#[should_panic(expected = "mask too short")]
+fn uint256_cmp_slice_short_mask() {
+    let a = values(1, 65);
+    let mut mask = [0u64; 1];
+    batch::cmp_slice(&a, &a, Ordering::Equal, &mut mask);
+}
@@ -0,0 +1,177 @@
+mod common;
+
+use rvv_simulator_runtime::{uint, DecodeError, Uint};
+
+type U64 = Uint<1>;
+type U128 = Uint<2>;
+type U256 = Uint<4>;
+
+const FQ: U256 = uint!("0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47");
+
+fn samples() -> Vec<U256> {
+    let mut v = vec![
+        U256::zero(),
+        U256::one(),
+        U256::from(0x7fu64),
+        U256::from(0x80u64),
+        U256::from(0x100u64),
+        U256::MAX,
+        FQ,
+        U256::one() << 255,
+    ];
+    for (i, x) in common::words(common::SEED).take(32).enumerate() {
+        v.push(Uint([x, x.rotate_left(17), x.rotate_left(31), x]) >> (i * 8));
+    }
+    v
+}
+
+#[test]
+fn uint_fixed_bytes_round_trip() {
+    for x in samples() {
+        assert_eq!(U256::from_be_bytes(x.to_be_bytes()), x);
+        assert_eq!(U256::from_le_bytes(x.to_le_bytes()), x);
+        let mut be = x.to_be_bytes();
+        be.reverse();
+        assert_eq!(be, x.to_le_bytes());
+    }
+    let x = U128::from(0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10u128);
+    assert_eq!(
+        x.to_be_bytes(),
+        [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16]
+    );
+    assert_eq!(U128::from_be_bytes(x.to_be_bytes()), x);
+    assert_eq!(U64::from(0x0102u64).to_be_bytes(), [0, 0, 0, 0, 0, 0, 1, 2]);
+}
+
+#[test]
+fn uint_minimal_be() {
+    let mut buf = [0u8; 32];
+    assert_eq!(U256::zero().write_minimal_be(&mut buf), 0);
+    assert_eq!(U256::zero().minimal_be_len(), 0);
+    assert_eq!(U256::from(0x7fu64).write_minimal_be(&mut buf), 1);
+    assert_eq!(buf[0], 0x7f);
+    assert_eq!(U256::from(0x0400u64).write_minimal_be(&mut buf), 2);
+    assert_eq!(&buf[..2], &[4, 0]);
+    assert_eq!(U256::MAX.minimal_be_len(), 32);
+
+    for x in samples() {
+        let len = x.write_minimal_be(&mut buf);
+        assert_eq!(len, x.minimal_be_len());
+        assert_eq!(&buf[..len], &x.to_be_bytes()[32 - len..]);
+        assert_eq!(U256::from_minimal_be(&buf[..len]), Ok(x));
+    }
+
+    assert_eq!(U256::from_minimal_be(&[]), Ok(U256::zero()));
+    assert_eq!(U256::from_minimal_be(&[0]), Err(DecodeError::LeadingZero));
+    assert_eq!(
+        U256::from_minimal_be(&[0, 1]),
+        Err(DecodeError::LeadingZero)
+    );
+    assert_eq!(
+        U256::from_minimal_be(&[1; 33]),
+        Err(DecodeError::InvalidLength)
+    );
+}
+
+#[test]
+#[should_panic]
+fn uint_minimal_be_short_buffer() {
+    let mut buf = [0u8; 1];
+    U256::from(0x100u64).write_minimal_be(&mut buf);
+}
+
+#[test]
+fn uint_ssz() {
+    let mut buf = [0u8; 32];
+    U256::from(0x0102u64).write_ssz(&mut buf);
+    assert_eq!(&buf[..3], &[2, 1, 0]);
+    for x in samples() {
+        x.write_ssz(&mut buf);
+        assert_eq!(buf, x.to_le_bytes());
+        assert_eq!(U256::from_ssz(&buf), Ok(x));
+    }
+    assert_eq!(U256::from_ssz(&buf[..31]), Err(DecodeError::InvalidLength));
+    assert_eq!(U256::from_ssz(&[0; 33]), Err(DecodeError::InvalidLength));
+}
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::*;
+    use serde_test::{
+        assert_de_tokens, assert_de_tokens_error, assert_ser_tokens_error, assert_tokens,
+        Configure, Token,
+    };
+
+    #[test]
+    fn uint_serde_readable() {
+        assert_tokens(&U256::zero().readable(), &[Token::Str("0x0")]);
+        assert_tokens(&U256::from(0xabcu64).readable(), &[Token::Str("0xabc")]);
+        assert_tokens(
+            &FQ.readable(),
+            &[Token::Str(
+                "0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47",
+            )],
+        );
+        assert_tokens(
+            &U128::MAX.readable(),
+            &[Token::Str("0xffffffffffffffffffffffffffffffff")],
+        );
+        // Leading zeros and a missing prefix are accepted
+        assert_de_tokens(&U128::from(10u64).readable(), &[Token::Str("000a")]);
+        assert_de_tokens_error::<serde_test::Readable<U128>>(
+            &[Token::Str("0xg")],
+            "Invalid character 'g' at position 0",
+        );
+    }
+
+    #[test]
+    fn uint_serde_compact() {
+        assert_tokens(
+            &U128::from(0x0102u64).compact(),
+            &[Token::Bytes(&[
+                0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2,
+            ])],
+        );
+        assert_tokens(&U64::MAX.compact(), &[Token::Bytes(&[0xff; 8])]);
+        assert_de_tokens(
+            &U64::from(0x0102u64).compact(),
+            &[
+                Token::Seq { len: Some(8) },
+                Token::U8(0),
+                Token::U8(0),
+                Token::U8(0),
+                Token::U8(0),
+                Token::U8(0),
+                Token::U8(0),
+                Token::U8(1),
+                Token::U8(2),
+                Token::SeqEnd,
+            ],
+        );
+        assert_de_tokens_error::<serde_test::Compact<U64>>(
+            &[Token::Bytes(&[1, 2])],
+            "invalid length 2, expected 8 big-endian bytes",
+        );
+    }
+
+    #[test]
+    fn uint_serde_compact_largest() {
+        // Big-endian bytes 0, 1, ..., 255, 0, 1, ... at the largest size
+        let bytes: Vec<u8> = (0..512).map(|i| i as u8).collect();
+        let x = bytes
+            .iter()
+            .fold(Uint::<64>::zero(), |x, &b| x << 8 | Uint::from(b as u64));
+        assert_eq!(x.0[63], 0x0001_0203_0405_0607);
+        assert_eq!(x.0[0], 0xf8f9_fafb_fcfd_feff);
+        let bytes: &'static [u8] = Box::leak(bytes.into_boxed_slice());
+        assert_tokens(&x.compact(), &[Token::Bytes(bytes)]);
+        assert_tokens(&Uint::<64>::MAX.compact(), &[Token::Bytes(&[0xff; 512])]);
+
+        assert_ser_tokens_error(
+            &Uint::<65>::one().compact(),
+            &[],
+            "compact serialization supports at most 64 words, not 65",
+        );
+        assert_tokens(&Uint::<65>::one().readable(), &[Token::Str("0x1")]);
+    }
+}
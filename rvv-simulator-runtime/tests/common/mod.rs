@@ -0,0 +1,60 @@
+// Fixtures shared by the integration tests. Not every test binary uses all
+// of them.
+#![allow(dead_code)]
+
+use rvv_simulator_runtime::Uint;
+
+/// Seed of the default sample sequence.
+pub const SEED: u64 = 0x9e37_79b9_7f4a_7c15;
+
+/// Endless xorshift sequence from `seed`. Cheap and deterministic, good
+/// enough to cover a spread of values.
+pub fn words(seed: u64) -> impl Iterator<Item = u64> {
+    let mut x = seed;
+    core::iter::repeat_with(move || {
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        x
+    })
+}
+
+/// Endless sequence of `Uint<N>` filled from `words(seed)`, lowest word first.
+pub fn uints<const N: usize>(seed: u64) -> impl Iterator<Item = Uint<N>> {
+    let mut words = words(seed);
+    core::iter::repeat_with(move || {
+        let mut a = [0u64; N];
+        for w in a.iter_mut() {
+            *w = words.next().unwrap();
+        }
+        Uint(a)
+    })
+}
+
+/// Schoolbook double-width product as `(low, high)`, one row per word of
+/// `b`. Doesn't share any code with `Uint::widening_mul`, so the kernels
+/// behind it can be checked against this.
+pub fn schoolbook<const N: usize>(a: Uint<N>, b: Uint<N>) -> (Uint<N>, Uint<N>) {
+    let mut lo = [0u64; N];
+    let mut hi = [0u64; N];
+    for i in 0..N {
+        let mut carry = 0u64;
+        for j in 0..N {
+            let w = if i + j < N {
+                &mut lo[i + j]
+            } else {
+                &mut hi[i + j - N]
+            };
+            let t = a.0[j] as u128 * b.0[i] as u128 + *w as u128 + carry as u128;
+            *w = t as u64;
+            carry = (t >> 64) as u64;
+        }
+        hi[i] = carry;
+    }
+    (Uint(lo), Uint(hi))
+}
+
+/// The first 64 words of the default sequence.
+pub fn samples() -> impl Iterator<Item = u64> {
+    words(SEED).take(64)
+}
@@ -0,0 +1,199 @@
+use rvv_simulator_runtime::{uint, Choice, ConstantTime, Montgomery, MontgomeryParams, Uint};
+
+type U256 = Uint<4>;
+
+// bn128 base field modulus
+const FQ: U256 = uint!("0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47");
+
+fn yes() -> Choice {
+    Choice::from(true)
+}
+
+fn no() -> Choice {
+    Choice::from(false)
+}
+
+#[test]
+fn uint256_ct_eq_lt() {
+    let a = uint!(4; "0x1_0000000000000000_0000000000000005");
+    let b = uint!(4; "0x1_0000000000000000_0000000000000006");
+    assert_eq!(a.ct_eq(&a), yes());
+    assert_eq!(a.ct_eq(&b), no());
+    assert_eq!(a.ct_lt(&b), yes());
+    assert_eq!(b.ct_lt(&a), no());
+    assert_eq!(a.ct_lt(&a), no());
+    assert_eq!(U256::zero().ct_lt(&U256::MAX), yes());
+    assert_eq!(U256::MAX.ct_eq(&U256::MAX), yes());
+    assert!(bool::from(!a.ct_eq(&b) & a.ct_lt(&b)));
+}
+
+#[test]
+fn uint256_ct_select_swap() {
+    let a = U256::from(1u64);
+    let b = U256::MAX;
+    assert_eq!(U256::ct_select(&a, &b, no()), a);
+    assert_eq!(U256::ct_select(&a, &b, yes()), b);
+
+    let (mut x, mut y) = (a, b);
+    U256::ct_swap(&mut x, &mut y, no());
+    assert_eq!((x, y), (a, b));
+    U256::ct_swap(&mut x, &mut y, yes());
+    assert_eq!((x, y), (b, a));
+}
+
+#[test]
+fn uint256_ct_conditional_sub() {
+    let a = U256::from(10u64);
+    let b = U256::from(3u64);
+    assert_eq!(a.ct_conditional_sub(&b, yes()), U256::from(7u64));
+    assert_eq!(a.ct_conditional_sub(&b, no()), a);
+    assert_eq!(b.ct_conditional_sub(&a, yes()), b.wrapping_sub(a));
+}
+
+#[test]
+fn uint256_ct_pow() {
+    assert_eq!(
+        U256::from(3u64).ct_pow(&U256::from(5u64)),
+        U256::from(243u64)
+    );
+    assert_eq!(U256::from(7u64).ct_pow(&U256::zero()), U256::one());
+    assert_eq!(
+        U256::from(2u64).ct_pow(&U256::from(255u64)),
+        U256::one() << 255
+    );
+    // wraps like `overflowing_pow`
+    let (expected, _) = U256::from(3u64).overflowing_pow(U256::from(1000u64));
+    assert_eq!(U256::from(3u64).ct_pow(&U256::from(1000u64)), expected);
+}
+
+#[test]
+fn montgomery_ct_pow() {
+    let params = MontgomeryParams::new(FQ).unwrap();
+    let x = Montgomery::new(uint!("0x1234567890abcdef1234567890abcdef"), &params);
+    for e in &[
+        U256::zero(),
+        U256::one(),
+        U256::from(65537u64),
+        FQ - U256::from(2u64),
+    ] {
+        assert_eq!(x.ct_pow(*e), x.pow(*e));
+    }
+}
+
+#[cfg(feature = "ct_trace")]
+mod trace {
+    use super::*;
+    use rvv_simulator_runtime::ct::trace;
+
+    // Runs `f` on every input and checks the recorded operations match.
+    fn assert_same_trace<T: Copy>(inputs: &[T], f: impl Fn(T)) {
+        trace::take();
+        f(inputs[0]);
+        let expected = trace::take();
+        assert!(!expected.is_empty());
+        for input in &inputs[1..] {
+            f(*input);
+            assert_eq!(trace::take(), expected);
+        }
+    }
+
+    #[test]
+    fn uint256_ct_trace_cmp() {
+        let inputs = [
+            (U256::zero(), U256::zero()),
+            (U256::zero(), U256::MAX),
+            (U256::MAX, U256::one()),
+            (U256::from(5u64), U256::from(5u64)),
+        ];
+        assert_same_trace(&inputs, |(a, b)| {
+            a.ct_eq(&b);
+        });
+        assert_same_trace(&inputs, |(a, b)| {
+            a.ct_lt(&b);
+        });
+        assert_same_trace(&inputs, |(a, b)| {
+            a.ct_conditional_sub(&b, a.ct_lt(&b));
+        });
+        assert_same_trace(&inputs, |(mut a, mut b)| {
+            let c = a.ct_eq(&b);
+            U256::ct_swap(&mut a, &mut b, c);
+            U256::ct_select(&a, &b, !c);
+        });
+    }
+
+    #[test]
+    fn uint256_ct_trace_pow() {
+        let exps = [
+            U256::zero(),
+            U256::one(),
+            U256::MAX,
+            U256::one() << 255,
+            uint!("0xaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"),
+        ];
+        assert_same_trace(&exps, |e| {
+            U256::from(3u64).ct_pow(&e);
+        });
+    }
+
+    #[test]
+    fn montgomery_ct_trace() {
+        let params = MontgomeryParams::new(FQ).unwrap();
+        let inputs = [
+            (U256::zero(), U256::zero()),
+            (FQ - U256::one(), FQ - U256::one()),
+            (U256::one(), U256::from(65537u64)),
+        ];
+        assert_same_trace(&inputs, |(x, e)| {
+            Montgomery::new(x, &params).ct_pow(e);
+        });
+    }
+
+    #[test]
+    fn uint_ct_trace_wide() {
+        // Above 8 words `widening_mul` goes through Karatsuba, whose half
+        // differences change sign with the operands
+        let lo = Uint::<40>::MAX >> (20 * 64);
+        let hi = Uint::<40>::MAX << (20 * 64);
+        let inputs = [
+            (Uint::<40>::zero(), Uint::<40>::zero()),
+            (lo, hi),
+            (hi, lo),
+            (hi, hi),
+            (Uint::<40>::MAX, Uint::<40>::one()),
+        ];
+        assert_same_trace(&inputs, |(a, b)| {
+            a.widening_mul(b);
+        });
+
+        let exps = [
+            Uint::<12>::zero(),
+            Uint::<12>::MAX,
+            Uint::<12>::one() << 700,
+        ];
+        assert_same_trace(&exps, |e| {
+            Uint::<12>::from(3u64).ct_pow(&e);
+        });
+
+        let params = MontgomeryParams::new(Uint::<12>::MAX).unwrap();
+        let inputs = [
+            (Uint::<12>::zero(), Uint::<12>::zero()),
+            (Uint::<12>::MAX - Uint::one(), Uint::<12>::MAX),
+            (Uint::<12>::from(7u64), Uint::<12>::one() << 700),
+        ];
+        assert_same_trace(&inputs, |(x, e)| {
+            Montgomery::new(x, &params).ct_pow(e);
+        });
+    }
+
+    #[test]
+    fn trace_detects_branching_pow() {
+        // Square and multiply skips the multiplication for zero bits
+        let params = MontgomeryParams::new(FQ).unwrap();
+        let x = Montgomery::new(U256::from(3u64), &params);
+        trace::take();
+        x.pow(U256::one() << 8);
+        let sparse = trace::take();
+        x.pow(U256::from(0x1ffu64));
+        assert_ne!(trace::take(), sparse);
+    }
+}
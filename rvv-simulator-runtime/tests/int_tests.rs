@@ -0,0 +1,170 @@
+mod common;
+
+use core::convert::TryFrom;
+use rvv_simulator_runtime::{Int, Uint};
+
+type I128 = Int<2>;
+type I256 = Int<4>;
+type U256 = Uint<4>;
+
+fn samples() -> Vec<i128> {
+    let mut v = vec![0, 1, -1, 2, -2, 7, -7, i128::MAX, i128::MIN, i128::MIN + 1];
+    for x in common::words(common::SEED).take(24) {
+        let small = (x >> 40) as i64 as i128;
+        v.push(small);
+        v.push(((x as i128) << 64 | x.rotate_left(17) as i128) >> (x % 64));
+    }
+    v
+}
+
+// Int<2> must behave exactly like i128
+#[test]
+fn int128_matches_i128() {
+    let s = samples();
+    for &a in &s {
+        for &b in &s {
+            let (ia, ib) = (I128::from(a), I128::from(b));
+            let same = |x: I128, y: i128| i128::try_from(x).unwrap() == y;
+            assert!(same(ia.wrapping_add(ib), a.wrapping_add(b)));
+            assert_eq!(ia.overflowing_add(ib).1, a.overflowing_add(b).1);
+            assert!(same(ia.wrapping_sub(ib), a.wrapping_sub(b)));
+            assert_eq!(ia.overflowing_sub(ib).1, a.overflowing_sub(b).1);
+            assert!(same(ia.wrapping_mul(ib), a.wrapping_mul(b)));
+            assert_eq!(
+                ia.overflowing_mul(ib).1,
+                a.overflowing_mul(b).1,
+                "{} * {}",
+                a,
+                b
+            );
+            assert_eq!(ia.cmp(&ib), a.cmp(&b));
+            assert_eq!(
+                ia.checked_div(ib).map(|x| i128::try_from(x).unwrap()),
+                a.checked_div(b)
+            );
+            assert_eq!(
+                ia.checked_rem(ib).map(|x| i128::try_from(x).unwrap()),
+                a.checked_rem(b)
+            );
+        }
+        let ia = I128::from(a);
+        assert_eq!(
+            ia.checked_abs().map(|x| i128::try_from(x).unwrap()),
+            a.checked_abs()
+        );
+        assert_eq!(
+            ia.checked_neg().map(|x| i128::try_from(x).unwrap()),
+            a.checked_neg()
+        );
+        assert_eq!(ia.unsigned_abs().low_u128(), a.unsigned_abs());
+        assert_eq!(i128::try_from(ia.signum()).unwrap(), a.signum());
+        assert_eq!(format!("{}", ia), format!("{}", a));
+        assert_eq!(format!("{:x}", ia), format!("{:x}", a));
+        for shift in &[0u32, 1, 63, 64, 100, 127] {
+            assert_eq!(i128::try_from(ia >> *shift).unwrap(), a >> shift);
+            assert_eq!(i128::try_from(ia << *shift).unwrap(), a << shift);
+        }
+    }
+}
+
+#[test]
+fn int256_arith() {
+    let a = I256::from(-7i64);
+    let b = I256::from(2i64);
+    assert_eq!(a / b, I256::from(-3i64));
+    assert_eq!(a % b, I256::from(-1i64));
+    assert_eq!(-a / b, I256::from(3i64));
+    assert_eq!(a % -b, I256::from(-1i64));
+    assert_eq!(a * b, I256::from(-14i64));
+    assert_eq!(a + b, I256::from(-5i64));
+    assert_eq!(b - a, I256::from(9i64));
+    assert_eq!(a.abs(), I256::from(7i64));
+    assert_eq!(a.unsigned_abs(), U256::from(7u64));
+    assert!(a.is_negative() && !a.is_positive());
+    assert!(b.is_positive());
+    assert!(a < b && I256::MIN < a && b < I256::MAX);
+    assert_eq!(format!("{:>5}", a), "   -7");
+    assert_eq!(format!("{:+}", b), "+2");
+    assert_eq!(format!("{:05}", a), "-0007");
+    assert_eq!(
+        format!("{}", I256::MIN),
+        "-57896044618658097711785492504343953926634992332820282019728792003956564819968"
+    );
+    assert_eq!(I256::minus_one().to_bits(), U256::MAX);
+    assert_eq!(I256::MIN >> 255u32, I256::minus_one());
+    assert_eq!(I256::MAX >> 254u32, I256::one());
+}
+
+#[test]
+fn int256_overflow() {
+    assert_eq!(I256::MAX.checked_add(I256::one()), None);
+    assert_eq!(I256::MIN.checked_sub(I256::one()), None);
+    assert_eq!(I256::MIN.checked_mul(I256::minus_one()), None);
+    assert_eq!(I256::MIN.checked_div(I256::minus_one()), None);
+    assert_eq!(I256::MIN.checked_abs(), None);
+    assert_eq!(I256::MIN.wrapping_abs(), I256::MIN);
+    assert_eq!(I256::MIN.wrapping_neg(), I256::MIN);
+    assert_eq!(I256::one().checked_div(I256::zero()), None);
+    assert_eq!(
+        I256::MIN.checked_mul(I256::one()),
+        Some(I256::MIN),
+        "MIN * 1 doesn't overflow"
+    );
+}
+
+#[test]
+#[should_panic]
+fn int256_abs_min_panics() {
+    I256::MIN.abs();
+}
+
+#[test]
+#[should_panic]
+fn int256_div_overflow_panics() {
+    let _ = I256::MIN / I256::minus_one();
+}
+
+#[test]
+fn int256_conversions() {
+    assert_eq!(I256::try_from(U256::from(5u64)), Ok(I256::from(5i32)));
+    assert!(I256::try_from(U256::MAX).is_err());
+    assert_eq!(U256::try_from(I256::from(5i8)), Ok(U256::from(5u64)));
+    assert!(U256::try_from(I256::from(-5i8)).is_err());
+    assert_eq!(i64::try_from(I256::from(i64::MIN)), Ok(i64::MIN));
+    assert!(i64::try_from(I256::from(i64::MAX) + I256::one()).is_err());
+    assert!(i8::try_from(I256::from(-129i32)).is_err());
+    assert_eq!(i8::try_from(I256::from(-128i32)), Ok(-128i8));
+    assert_eq!(I256::from_bits(U256::MAX), I256::minus_one());
+    assert_eq!(I256::from(-1i8).to_bits(), U256::MAX);
+}
+
+#[test]
+fn int64_int128_conversion_bounds() {
+    type I64 = Int<1>;
+    assert_eq!(i64::try_from(I64::MAX), Ok(i64::MAX));
+    assert_eq!(i64::try_from(I64::MIN), Ok(i64::MIN));
+    assert_eq!(i128::try_from(I64::MIN), Ok(i64::MIN as i128));
+    assert_eq!(i128::try_from(I64::minus_one()), Ok(-1));
+    assert_eq!(i8::try_from(I64::from(-128i8)), Ok(-128));
+    assert!(i8::try_from(I64::from(128i16)).is_err());
+    assert!(i32::try_from(I64::MIN).is_err());
+
+    // Only the `i128` values that fit in one word convert
+    assert_eq!(I64::try_from(i64::MIN as i128), Ok(I64::MIN));
+    assert_eq!(I64::try_from(i64::MAX as i128), Ok(I64::MAX));
+    assert_eq!(I64::try_from(-1i128), Ok(I64::minus_one()));
+    assert!(I64::try_from(i64::MAX as i128 + 1).is_err());
+    assert!(I64::try_from(i64::MIN as i128 - 1).is_err());
+    assert!(I64::try_from(i128::MIN).is_err());
+
+    assert_eq!(I128::from(i128::MIN), I128::MIN);
+    assert_eq!(I128::from(i128::MAX), I128::MAX);
+    assert_eq!(i128::try_from(I128::MIN), Ok(i128::MIN));
+    assert_eq!(i128::try_from(I128::MAX), Ok(i128::MAX));
+    assert_eq!(i64::try_from(I128::from(i64::MIN)), Ok(i64::MIN));
+    assert!(i64::try_from(I128::from(i64::MIN as i128 - 1)).is_err());
+    assert!(i64::try_from(I128::from(1i128 << 64)).is_err());
+    assert_eq!(I256::from(i128::MIN).to_bits() >> 127, U256::MAX >> 127);
+    assert!(i128::try_from(I256::from(i128::MAX) + I256::one()).is_err());
+    assert!(i128::try_from(I256::from(i128::MIN) - I256::one()).is_err());
+}
@@ -0,0 +1,72 @@
+use rvv_simulator_runtime::{uint, Uint, UintSum};
+
+type U256 = Uint<4>;
+
+const FQ: U256 = uint!("0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47");
+
+fn u(v: u64) -> U256 {
+    U256::from(v)
+}
+
+#[test]
+fn uint_sum_product() {
+    let xs = [u(1), u(2), u(3), u(4)];
+    assert_eq!(xs.iter().sum::<U256>(), u(10));
+    assert_eq!(xs.iter().copied().sum::<U256>(), u(10));
+    assert_eq!(xs.iter().product::<U256>(), u(24));
+    assert_eq!(xs.iter().copied().product::<U256>(), u(24));
+
+    let empty: [U256; 0] = [];
+    assert_eq!(empty.iter().sum::<U256>(), U256::zero());
+    assert_eq!(empty.iter().product::<U256>(), U256::one());
+
+    // Carries across limbs
+    let halves = [U256::MAX >> 1, U256::MAX >> 1, U256::one()];
+    assert_eq!(halves.iter().sum::<U256>(), U256::MAX);
+    let limb = U256::one() << 64;
+    assert_eq!(
+        [limb, limb, limb].iter().product::<U256>(),
+        U256::one() << 192
+    );
+}
+
+#[test]
+#[should_panic(expected = "arithmetic operation overflow")]
+fn uint_sum_overflow() {
+    let _: U256 = [U256::MAX, U256::one()].iter().sum();
+}
+
+#[test]
+#[should_panic(expected = "arithmetic operation overflow")]
+fn uint_product_overflow() {
+    let _: U256 = [U256::one() << 128, U256::one() << 128].iter().product();
+}
+
+#[test]
+fn uint_sum_policies() {
+    let xs = [U256::MAX, u(5), u(7)];
+    assert_eq!(xs.iter().checked_sum(), None);
+    assert_eq!(xs[1..].iter().checked_sum(), Some(u(12)));
+    assert_eq!(xs.iter().copied().checked_sum(), None);
+    assert_eq!(xs.iter().wrapping_sum(), u(11));
+    assert_eq!(xs.iter().copied().wrapping_sum(), u(11));
+    assert_eq!(core::iter::empty::<U256>().wrapping_sum(), U256::zero());
+
+    // Terms don't need to be reduced, and the sum never overflows
+    let terms = [U256::MAX, FQ, FQ - u(1), U256::MAX, u(3)];
+    let mut expected = U256::zero();
+    for t in terms.iter() {
+        expected = expected.add_mod(*t, FQ);
+    }
+    assert_eq!(terms.iter().mod_sum(FQ), expected);
+    assert_eq!(terms.iter().copied().mod_sum(FQ), expected);
+    assert_eq!([FQ - u(1), u(1)].iter().mod_sum(FQ), U256::zero());
+    assert_eq!(core::iter::empty::<U256>().mod_sum(u(1)), U256::zero());
+    assert_eq!((0..100u64).map(u).mod_sum(u(7)), u(4950 % 7));
+}
+
+#[test]
+#[should_panic]
+fn uint_mod_sum_zero_modulus() {
+    core::iter::empty::<U256>().mod_sum(U256::zero());
+}
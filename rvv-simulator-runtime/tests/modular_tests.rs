@@ -0,0 +1,122 @@
+mod common;
+
+use common::samples;
+use rvv_simulator_runtime::{uint, Uint};
+
+type U256 = Uint<4>;
+
+// bn128 base field modulus (p = 3 mod 4)
+const FQ: U256 = uint!("0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47");
+// bn128 scalar field modulus (r - 1 = q * 2^28)
+const FR: U256 = uint!("0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001");
+
+fn u256(v: u128) -> U256 {
+    U256::from(v)
+}
+
+#[test]
+fn uint256_add_sub_mod_match_u128() {
+    let m = 0xffff_ffff_ffff_ffc5u64 as u128;
+    for (a, b) in samples().zip(samples().skip(1)) {
+        let (a, b) = (a as u128, b as u128);
+        assert_eq!(u256(a).add_mod(u256(b), u256(m)), u256((a + b) % m));
+        assert_eq!(
+            u256(a).sub_mod(u256(b), u256(m)),
+            u256((a % m + m - b % m) % m)
+        );
+    }
+}
+
+#[test]
+fn uint256_add_mod_overflow() {
+    let m = U256::MAX - U256::from(1u64);
+    let a = U256::MAX - U256::from(5u64);
+    // (m - 4) + (m - 4) = m - 8 (mod m)
+    assert_eq!(a.add_mod(a, m), m - U256::from(8u64));
+    assert_eq!(U256::MAX.add_mod(U256::zero(), m), U256::one());
+}
+
+#[test]
+fn uint256_mul_mod_matches_u128() {
+    let m = 0xffff_ffff_ffff_ffc5u64 as u128;
+    for (a, b) in samples().zip(samples().skip(3)) {
+        let (a, b) = (a as u128, b as u128);
+        assert_eq!(u256(a).mul_mod(u256(b), u256(m)), u256(a * b % m));
+    }
+}
+
+#[test]
+fn uint256_mul_mod_wide() {
+    let p = FQ;
+    let a = U256::MAX;
+    // (2^256 - 1)^2 mod p, checked against `(a % p)^2 % p`
+    let r = a % p;
+    assert_eq!(a.mul_mod(a, p), r.mul_mod(r, p));
+    // (p - 1)^2 == 1
+    let m1 = p - U256::one();
+    assert_eq!(m1.mul_mod(m1, p), U256::one());
+}
+
+#[test]
+fn uint256_pow_mod() {
+    let p = FQ;
+    assert_eq!(U256::from(3u64).pow_mod(U256::zero(), p), U256::one());
+    assert_eq!(
+        U256::from(3u64).pow_mod(U256::from(5u64), p),
+        U256::from(243u64)
+    );
+    assert_eq!(
+        U256::from(7u64).pow_mod(U256::from(9u64), U256::one()),
+        U256::zero()
+    );
+    // Fermat's little theorem
+    for a in samples().take(8) {
+        assert_eq!(U256::from(a).pow_mod(p - U256::one(), p), U256::one());
+    }
+    let m = 1_000_000_007u128;
+    let mut expected = 1u128;
+    for _ in 0..1000 {
+        expected = expected * 12345 % m;
+    }
+    assert_eq!(u256(12345).pow_mod(u256(1000), u256(m)), u256(expected));
+}
+
+#[test]
+fn uint256_inv_mod() {
+    let p = FQ;
+    for a in samples().take(16) {
+        let a = U256::from(a);
+        let inv = a.inv_mod(p).unwrap();
+        assert_eq!(a.mul_mod(inv, p), U256::one());
+        assert_eq!(inv, a.pow_mod(p - U256::from(2u64), p));
+    }
+    assert_eq!(
+        U256::from(3u64).inv_mod(U256::from(10u64)),
+        Some(U256::from(7u64))
+    );
+    assert_eq!(U256::from(4u64).inv_mod(U256::from(10u64)), None);
+    assert_eq!(U256::zero().inv_mod(p), None);
+    assert_eq!(U256::from(3u64).inv_mod(U256::zero()), None);
+    assert_eq!(U256::from(3u64).inv_mod(U256::one()), Some(U256::zero()));
+}
+
+#[test]
+fn uint256_sqrt_mod() {
+    for &p in &[FQ, FR] {
+        for a in samples().take(8) {
+            let a = U256::from(a);
+            let sq = a.mul_mod(a, p);
+            let r = sq.sqrt_mod(p).unwrap();
+            assert_eq!(r.mul_mod(r, p), sq);
+            assert!(r <= p - r);
+            assert!(r == a || r == p - a);
+        }
+        assert_eq!(U256::zero().sqrt_mod(p), Some(U256::zero()));
+    }
+    // Known non-residues: -1 for Fq (p = 3 mod 4), the generator 5 for Fr
+    assert_eq!((FQ - U256::one()).sqrt_mod(FQ), None);
+    assert_eq!(U256::from(5u64).sqrt_mod(FR), None);
+    assert_eq!(U256::from(4u64).sqrt_mod(u256(13)), Some(U256::from(2u64)));
+    assert_eq!(U256::from(5u64).sqrt_mod(u256(13)), None);
+    assert_eq!(U256::one().sqrt_mod(u256(2)), Some(U256::one()));
+}
@@ -0,0 +1,144 @@
+mod common;
+
+use rvv_simulator_runtime::{uint, Montgomery, MontgomeryParams, Uint};
+
+type U256 = Uint<4>;
+type U512 = Uint<8>;
+
+// bn128 base field modulus
+const FQ: U256 = uint!("0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47");
+
+// Below 2^253, so already reduced mod FQ
+fn samples() -> impl Iterator<Item = U256> {
+    common::uints(common::SEED).take(32).map(|x: U256| x >> 3)
+}
+
+#[test]
+fn montgomery_params_bn128() {
+    let p = FQ;
+    let params = MontgomeryParams::new(p).unwrap();
+    // Same constants as the bn128 `fields::fp` Fq
+    assert_eq!(params.n_prime(), 0x87d20782e4866389);
+    assert_eq!(
+        params.r(),
+        uint!("0xe0a77c19a07df2f666ea36f7879462c0a78eb28f5c70b3dd35d438dc58f0d9d")
+    );
+    assert_eq!(
+        params.r2(),
+        uint!("0x6d89f71cab8351f47ab1eff0a417ff6b5e71911d44501fbf32cfc5b538afa89")
+    );
+    assert_eq!(
+        params.n_prime().wrapping_mul(p.0[0]),
+        u64::MAX,
+        "n * n' == -1 mod 2^64"
+    );
+    assert!(MontgomeryParams::new(p + U256::one()).is_none());
+
+    const PARAMS: MontgomeryParams<4> = MontgomeryParams::from_raw_parts(
+        FQ,
+        0x87d20782e4866389,
+        uint!("0xe0a77c19a07df2f666ea36f7879462c0a78eb28f5c70b3dd35d438dc58f0d9d"),
+        uint!("0x6d89f71cab8351f47ab1eff0a417ff6b5e71911d44501fbf32cfc5b538afa89"),
+    );
+    assert_eq!(PARAMS, params);
+}
+
+#[test]
+fn montgomery_matches_mul_mod() {
+    let p = FQ;
+    let params = MontgomeryParams::new(p).unwrap();
+    for (a, b) in samples().zip(samples().skip(1)) {
+        let (a, b) = (a % p, b % p);
+        let am = Montgomery::new(a, &params);
+        let bm = Montgomery::new(b, &params);
+        assert_eq!(am.retrieve(), a);
+        assert_eq!((am * bm).retrieve(), a.mul_mod(b, p));
+        assert_eq!((am + bm).retrieve(), a.add_mod(b, p));
+        assert_eq!((am - bm).retrieve(), a.sub_mod(b, p));
+        assert_eq!((-am).retrieve(), U256::zero().sub_mod(a, p));
+    }
+}
+
+#[test]
+fn montgomery_mul_unreduced_a() {
+    // Only `b` has to be below the modulus, including a full-width one
+    for &p in [FQ, U256::MAX - U256::from(188u64)].iter() {
+        let params = MontgomeryParams::new(p).unwrap();
+        let mut xs: Vec<U256> = common::uints(common::SEED).take(32).collect();
+        xs.extend_from_slice(&[U256::MAX, U256::MAX - U256::one(), p, p - U256::one()]);
+        for (&a, &b) in xs.iter().zip(xs.iter().rev()) {
+            let b = b % p;
+            let r = params.mul(a, b);
+            assert!(r < p, "{:x} * {:x} mod {:x}", a, b, p);
+            assert_eq!(r, params.mul(a % p, b));
+        }
+        assert_eq!(params.from_mont(U256::MAX), params.from_mont(U256::MAX % p));
+    }
+}
+
+#[test]
+fn montgomery_pow_inv() {
+    let p = FQ;
+    let params = MontgomeryParams::new(p).unwrap();
+    let e = U256::from(65537u64);
+    for a in samples().take(8) {
+        let am = Montgomery::new(a, &params);
+        assert_eq!(am.pow(e).retrieve(), a.pow_mod(e, p));
+        let inv = am.inv().unwrap();
+        assert_eq!(inv * am, Montgomery::one(&params));
+        assert_eq!(inv.retrieve(), a.inv_mod(p).unwrap());
+    }
+    assert_eq!(Montgomery::zero(&params).inv(), None);
+    assert_eq!(
+        Montgomery::new(U256::from(7u64), &params).pow(U256::zero()),
+        Montgomery::one(&params)
+    );
+}
+
+#[test]
+fn montgomery_assign_ops() {
+    let p = FQ;
+    let params = MontgomeryParams::new(p).unwrap();
+    let a = Montgomery::new(U256::from(5u64), &params);
+    let mut x = Montgomery::one(&params);
+    x += a;
+    x *= &a;
+    x -= Montgomery::one(&params);
+    assert_eq!(x.retrieve(), U256::from(29u64));
+}
+
+#[test]
+fn montgomery_full_width_modulus() {
+    // Largest odd moduli exercise the extra carry words
+    let n = U256::MAX;
+    let params = MontgomeryParams::new(n).unwrap();
+    let a = n - U256::one();
+    let am = Montgomery::new(a, &params);
+    assert_eq!((am * am).retrieve(), U256::one());
+
+    let n = U512::MAX - U512::from(2u64);
+    let params = MontgomeryParams::new(n).unwrap();
+    let a = n - U512::from(3u64);
+    let b = n - U512::from(5u64);
+    let r = Montgomery::new(a, &params) * Montgomery::new(b, &params);
+    assert_eq!(r.retrieve(), U512::from(15u64));
+}
+
+#[test]
+fn montgomery_slices() {
+    let p = FQ;
+    let params = MontgomeryParams::new(p).unwrap();
+    let a: Vec<U256> = samples().take(8).map(|x| x % p).collect();
+    let b: Vec<U256> = samples().skip(8).take(8).map(|x| x % p).collect();
+    let mut am = vec![U256::zero(); 8];
+    let mut bm = vec![U256::zero(); 8];
+    params.to_mont_slice(&a, &mut am);
+    params.to_mont_slice(&b, &mut bm);
+    let mut prod = vec![U256::zero(); 8];
+    params.mul_slice(&am, &bm, &mut prod);
+    let mut out = vec![U256::zero(); 8];
+    params.from_mont_slice(&prod, &mut out);
+    for i in 0..8 {
+        assert_eq!(out[i], a[i].mul_mod(b[i], p));
+    }
+}
@@ -0,0 +1,176 @@
+mod common;
+
+use common::samples;
+use rvv_simulator_runtime::{uint, Int, Uint};
+
+type U256 = Uint<4>;
+
+// bn128 base field and scalar field moduli
+const FQ: U256 = uint!("0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47");
+const FR: U256 = uint!("0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001");
+
+fn u256(v: u128) -> U256 {
+    U256::from(v)
+}
+
+fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+#[test]
+fn uint256_gcd_matches_u128() {
+    for (a, b) in samples().zip(samples().skip(3)) {
+        // Share some factors so the gcd isn't always 1
+        let a = (a as u128) * 360;
+        let b = (b as u128) * 84;
+        let g = u256(gcd_u128(a, b));
+        assert_eq!(u256(a).gcd(u256(b)), g);
+        assert_eq!(u256(a).binary_gcd(u256(b)), g);
+    }
+    assert_eq!(U256::zero().gcd(U256::zero()), U256::zero());
+    assert_eq!(U256::zero().binary_gcd(u256(12)), u256(12));
+    assert_eq!(u256(12).binary_gcd(U256::zero()), u256(12));
+    assert_eq!(U256::MAX.binary_gcd(U256::MAX), U256::MAX);
+    assert_eq!(FQ.gcd(FR), U256::one());
+    let m89 = u256((1 << 89) - 1);
+    assert_eq!((m89 << 3).binary_gcd(m89 << 5), m89 << 3);
+    assert_eq!((m89 << 3).gcd(m89 << 5), m89 << 3);
+}
+
+#[test]
+fn uint256_extended_gcd_bezout() {
+    let pairs = [
+        (u256(240), u256(46)),
+        (u256(46), u256(240)),
+        (u256(7), U256::zero()),
+        (U256::zero(), u256(7)),
+        (U256::zero(), U256::zero()),
+        (FQ, FR),
+        (U256::MAX, U256::MAX - U256::one()),
+        (U256::MAX, u256(1) << 255),
+        (u256((1 << 89) - 1) * u256(6 << 64), u256(10 << 64)),
+    ];
+    for &(a, b) in pairs.iter() {
+        let (g, x, y) = a.extended_gcd(b);
+        assert_eq!(g, a.gcd(b));
+        // a * x + b * y == g holds modulo 2^256, and the coefficients are small
+        let lhs = a
+            .wrapping_mul(x.to_bits())
+            .wrapping_add(b.wrapping_mul(y.to_bits()));
+        assert_eq!(lhs, g, "{} {}", a, b);
+        if !g.is_zero() {
+            assert!(x.unsigned_abs() <= ((b / g) >> 1).max(U256::one()));
+            assert!(y.unsigned_abs() <= ((a / g) >> 1).max(U256::one()));
+        }
+    }
+    let (g, x, y) = u256(240).extended_gcd(u256(46));
+    assert_eq!(g, u256(2));
+    assert_eq!(x, Int::from(-9i64));
+    assert_eq!(y, Int::from(47i64));
+}
+
+#[test]
+fn uint256_lcm() {
+    assert_eq!(u256(4).lcm(u256(6)), u256(12));
+    assert_eq!(u256(21).lcm(u256(6)), u256(42));
+    assert_eq!(u256(5).lcm(U256::zero()), U256::zero());
+    assert_eq!(FQ.lcm(FQ), FQ);
+    assert_eq!(FQ.lcm(u256(2)), FQ << 1);
+}
+
+#[test]
+#[should_panic(expected = "arithmetic operation overflow")]
+fn uint256_lcm_overflow() {
+    FQ.lcm(FR);
+}
+
+#[test]
+fn uint256_jacobi() {
+    // Known values
+    assert_eq!(u256(1001).jacobi(u256(9907)), -1);
+    assert_eq!(u256(19).jacobi(u256(45)), 1);
+    assert_eq!(u256(8).jacobi(u256(21)), -1);
+    assert_eq!(u256(5).jacobi(u256(21)), 1);
+    assert_eq!(u256(6).jacobi(u256(15)), 0);
+    assert_eq!(U256::zero().jacobi(U256::one()), 1);
+    // Matches Euler's criterion modulo a prime
+    let half = (FQ - U256::one()) >> 1;
+    for a in samples() {
+        let a = u256(a as u128);
+        let euler = a.pow_mod(half, FQ);
+        let expected = if euler == U256::one() { 1 } else { -1 };
+        assert_eq!(a.jacobi(FQ), expected);
+        assert_eq!((a * a).jacobi(FQ), 1);
+    }
+    assert_eq!(FQ.jacobi(FQ), 0);
+}
+
+#[test]
+#[should_panic(expected = "odd modulus")]
+fn uint256_jacobi_even_modulus() {
+    u256(3).jacobi(u256(10));
+}
+
+#[test]
+fn uint256_is_probable_prime() {
+    let primes = [
+        u256(2),
+        u256(3),
+        u256(89),
+        u256(97),
+        u256(65537),
+        u256((1 << 61) - 1),
+        u256((1 << 89) - 1),
+        u256(u128::MAX >> 1),
+        FQ,
+        FR,
+        // largest 256-bit prime
+        U256::MAX - u256(188),
+    ];
+    for p in primes.iter() {
+        assert!(p.is_probable_prime(20), "{}", p);
+    }
+
+    let composites = [
+        U256::zero(),
+        U256::one(),
+        u256(4),
+        u256(91),
+        // Carmichael numbers
+        u256(561),
+        u256(41041),
+        // strong pseudoprime to bases 2, 3, 5 and 7
+        u256(3_215_031_751),
+        u256(((1 << 61) - 1) * ((1 << 61) - 1)),
+        u256((1 << 89) - 1) * u256((1 << 61) - 1),
+        FQ * u256(3),
+        U256::MAX,
+    ];
+    for c in composites.iter() {
+        assert!(!c.is_probable_prime(20), "{}", c);
+    }
+
+    // The smallest strong pseudoprimes to the first 12 and 13 prime bases
+    let psp12: U256 = uint!("318665857834031151167461");
+    assert!(psp12.is_probable_prime(12));
+    assert!(!psp12.is_probable_prime(13));
+    let psp13: U256 = uint!("3317044064679887385961981");
+    assert!(psp13.is_probable_prime(13));
+    assert!(!psp13.is_probable_prime(14));
+
+    // Rounds past the 24 available bases are capped
+    assert!(!psp13.is_probable_prime(100));
+    assert!(FQ.is_probable_prime(usize::MAX));
+}
+
+#[test]
+#[should_panic(expected = "is_probable_prime needs at least one round")]
+fn uint256_is_probable_prime_zero_rounds() {
+    // 97 * 101 has no factor the trial division catches
+    u256(9797).is_probable_prime(0);
+}
@@ -0,0 +1,132 @@
+mod common;
+
+use proptest::prelude::*;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use rvv_simulator_runtime::{uint, Uint};
+
+type U128 = Uint<2>;
+type U256 = Uint<4>;
+type U512 = Uint<8>;
+
+const FQ: U256 = uint!("0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47");
+
+#[test]
+fn uint_standard_sampling() {
+    let mut rng = SmallRng::seed_from_u64(1);
+    let xs: Vec<U256> = (0..64).map(|_| rng.gen()).collect();
+    for (i, x) in xs.iter().enumerate() {
+        assert!(xs[i + 1..].iter().all(|y| y != x));
+    }
+    // Every limb gets random bits, including the top one
+    for i in 0..4 {
+        assert!(xs.iter().any(|x| x.0[i] >> 63 == 1));
+        assert!(xs.iter().any(|x| x.0[i] >> 63 == 0));
+    }
+}
+
+#[test]
+fn uint_random_below() {
+    let mut rng = SmallRng::seed_from_u64(2);
+    for _ in 0..16 {
+        assert_eq!(U256::random_below(U256::one(), &mut rng), U256::zero());
+    }
+    let bounds = [
+        U256::from(2u64),
+        U256::from(3u64),
+        U256::from(1000u64),
+        U256::one() << 64,
+        (U256::one() << 64) + U256::one(),
+        U256::one() << 255,
+        FQ,
+        U256::MAX,
+    ];
+    for bound in bounds.iter() {
+        let mut seen_high = false;
+        for _ in 0..200 {
+            let x = U256::random_below(*bound, &mut rng);
+            assert!(x < *bound);
+            seen_high |= x >= *bound >> 1;
+        }
+        // The whole range is covered, not just the bottom of it
+        assert!(seen_high, "bound {:#x}", bound);
+    }
+    // Small bounds hit every value
+    let mut counts = [0; 3];
+    for _ in 0..300 {
+        counts[U256::random_below(U256::from(3u64), &mut rng).low_u64() as usize] += 1;
+    }
+    assert!(counts.iter().all(|&c| c > 50), "{:?}", counts);
+}
+
+#[test]
+#[should_panic(expected = "random_below with a zero bound")]
+fn uint_random_below_zero() {
+    let mut rng = SmallRng::seed_from_u64(3);
+    U256::random_below(U256::zero(), &mut rng);
+}
+
+proptest! {
+    #[test]
+    fn prop_u128_matches_native(a: u128, b: u128) {
+        let (x, y) = (U128::from(a), U128::from(b));
+        prop_assert_eq!(x.wrapping_add(y).as_u128(), a.wrapping_add(b));
+        prop_assert_eq!(x.wrapping_sub(y).as_u128(), a.wrapping_sub(b));
+        prop_assert_eq!(x.wrapping_mul(y).as_u128(), a.wrapping_mul(b));
+        prop_assert_eq!(x.overflowing_add(y).1, a.overflowing_add(b).1);
+        prop_assert_eq!(x.cmp(&y), a.cmp(&b));
+        prop_assert_eq!(x.checked_div(y).map(|q| q.as_u128()), a.checked_div(b));
+        prop_assert_eq!(x.checked_rem(y).map(|r| r.as_u128()), a.checked_rem(b));
+    }
+
+    #[test]
+    fn prop_add_sub_inverse(a: U256, b: U256) {
+        prop_assert_eq!(a.wrapping_add(b).wrapping_sub(b), a);
+        let (sum, carry) = a.overflowing_add(b);
+        prop_assert_eq!(carry, sum < a);
+    }
+
+    #[test]
+    fn prop_widening_mul(a: U512, b: U512) {
+        let (lo, hi) = a.widening_mul(b);
+        prop_assert_eq!((lo, hi), common::schoolbook(a, b));
+        prop_assert_eq!(lo, a.wrapping_mul(b));
+        prop_assert_eq!(b.widening_mul(a), (lo, hi));
+    }
+
+    #[test]
+    fn prop_div_mod(a: U256, b: U256) {
+        prop_assume!(!b.is_zero());
+        let (q, r) = a.div_mod(b);
+        prop_assert!(r < b);
+        prop_assert_eq!(q * b + r, a);
+    }
+
+    #[test]
+    fn prop_shift_round_trip(a: U256, shift in 0..256usize) {
+        prop_assert_eq!((a << shift) >> shift, a & (U256::MAX >> shift));
+    }
+
+    #[test]
+    fn prop_bytes_round_trip(a: U256) {
+        prop_assert_eq!(U256::from_be_bytes(a.to_be_bytes()), a);
+        let mut buf = [0u8; 32];
+        let len = a.write_minimal_be(&mut buf);
+        prop_assert_eq!(U256::from_minimal_be(&buf[..len]), Ok(a));
+    }
+
+    #[test]
+    fn prop_mul_mod(a: U256, b: U256) {
+        let r = a.mul_mod(b, FQ);
+        prop_assert!(r < FQ);
+        prop_assert_eq!(r, b.mul_mod(a, FQ));
+        prop_assert_eq!(a.mul_mod(U256::one(), FQ), a % FQ);
+    }
+
+    #[test]
+    fn prop_random_below(bound: U256, seed: u64) {
+        prop_assume!(!bound.is_zero());
+        let mut rng = SmallRng::seed_from_u64(seed);
+        prop_assert!(U256::random_below(bound, &mut rng) < bound);
+    }
+}
@@ -1,5 +1,7 @@
+mod common;
+
 use core::{convert::TryInto, str::FromStr, u64::MAX};
-use rvv_simulator_runtime::{overflowing, FromDecStrErr, Uint};
+use rvv_simulator_runtime::{overflowing, uint, FromDecStrErr, FromStrRadixErrKind, Uint};
 
 type U256 = Uint<4>;
 type U512 = Uint<8>;
@@ -104,6 +106,63 @@ fn uint256_checked_ops() {
     assert_eq!(z.checked_neg(), Some(z));
 }
 
+#[test]
+fn uint256_shift_neg_pow_family() {
+    let one = U256::one();
+    let a = U256::from(10);
+
+    assert_eq!(one.overflowing_shl(255), (one << 255, false));
+    assert_eq!(one.overflowing_shl(256), (one, true));
+    assert_eq!(one.overflowing_shl(257), (U256::from(2), true));
+    assert_eq!(one.checked_shl(255), Some(one << 255));
+    assert_eq!(one.checked_shl(256), None);
+    assert_eq!(one.wrapping_shl(260), U256::from(16));
+    assert_eq!(U256::MAX.overflowing_shr(256), (U256::MAX, true));
+    assert_eq!(U256::MAX.checked_shr(255), Some(one));
+    assert_eq!(U256::MAX.checked_shr(256), None);
+    assert_eq!(U256::from(16).wrapping_shr(260), one);
+
+    assert_eq!(a.overflowing_neg(), (U256::MAX - U256::from(9), true));
+    assert_eq!(a.wrapping_neg().wrapping_add(a), U256::zero());
+    assert_eq!(U256::zero().wrapping_neg(), U256::zero());
+    assert_eq!(one.wrapping_neg(), U256::MAX);
+
+    assert_eq!(a.wrapping_pow(U256::from(3)), U256::from(1000));
+    assert_eq!(U256::from(2).wrapping_pow(U256::from(256)), U256::zero());
+    assert_eq!(
+        U256::from(3).wrapping_pow(U256::from(200)),
+        U256::from(3).overflowing_pow(U256::from(200)).0
+    );
+    assert_eq!(a.saturating_pow(U256::from(2)), U256::from(100));
+    assert_eq!(a.saturating_pow(U256::from(100)), U256::MAX);
+
+    assert_eq!(a.abs_diff(U256::from(3)), U256::from(7));
+    assert_eq!(U256::from(3).abs_diff(a), U256::from(7));
+    assert_eq!(U256::MAX.abs_diff(U256::zero()), U256::MAX);
+}
+
+#[test]
+fn uint256_next_power_of_two() {
+    assert!(U256::from(64).is_power_of_two());
+    assert!(!U256::from(65).is_power_of_two());
+    assert!(!U256::zero().is_power_of_two());
+    assert_eq!(U256::zero().next_power_of_two(), U256::one());
+    assert_eq!(U256::one().next_power_of_two(), U256::one());
+    assert_eq!(U256::from(3).next_power_of_two(), U256::from(4));
+    assert_eq!(U256::from(64).next_power_of_two(), U256::from(64));
+    assert_eq!(U256::from(65).next_power_of_two(), U256::from(128));
+    let top = U256::one() << 255;
+    assert_eq!(top.checked_next_power_of_two(), Some(top));
+    assert_eq!((top + U256::one()).checked_next_power_of_two(), None);
+    assert_eq!(U256::MAX.checked_next_power_of_two(), None);
+}
+
+#[test]
+#[should_panic]
+fn uint256_next_power_of_two_overflow_panic() {
+    ((U256::one() << 255) + U256::one()).next_power_of_two();
+}
+
 #[test]
 fn uint256_from() {
     let e = U256!([10, 0, 0, 0]);
@@ -183,6 +242,38 @@ fn uint256_from() {
     );
 }
 
+#[test]
+fn uint256_from_str_errors() {
+    assert_eq!(U256::from_str("ABCdef").unwrap(), U256::from(0xabcdefu64));
+    assert_eq!(
+        U256::from_str("0x12g4").unwrap_err().to_string(),
+        "Invalid character 'g' at position 2"
+    );
+    assert_eq!(
+        U256::from_str("1é").unwrap_err().to_string(),
+        "Invalid character 'é' at position 1"
+    );
+    assert_eq!(
+        U256::from_str(&"f".repeat(65)).unwrap_err().to_string(),
+        "Invalid string length"
+    );
+    assert_eq!(U256::from_str(&"f".repeat(64)).unwrap(), U256::MAX);
+    assert_eq!(
+        U512::from_str(&"0".repeat(129)).unwrap_err(),
+        U512::from_str(&"0".repeat(130)).unwrap_err()
+    );
+    assert_eq!(
+        U256::from_str_radix("0x-1", 16).unwrap_err().kind(),
+        FromStrRadixErrKind::InvalidCharacter
+    );
+    assert_eq!(
+        U256::from_str_radix(&"1".repeat(65), 16)
+            .unwrap_err()
+            .kind(),
+        FromStrRadixErrKind::InvalidLength
+    );
+}
+
 #[test]
 fn uint256_try_into_primitives() {
     macro_rules! try_into_uint_primitive_ok {
@@ -539,6 +630,172 @@ fn uint256_mul_overflow() {
     );
 }
 
+#[test]
+fn uint256_widening_mul() {
+    let a =
+        U256::from_str("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff").unwrap();
+    assert_eq!(
+        a.widening_mul(a),
+        (
+            U256::one(),
+            U256::from_str("3fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff")
+                .unwrap()
+        )
+    );
+    assert_eq!(
+        U256::MAX.widening_mul(U256::MAX),
+        (U256::one(), U256::MAX - U256::one())
+    );
+    assert_eq!(
+        U256::from(3u64).widening_mul(U256::from(5u64)),
+        (U256::from(15u64), U256::zero())
+    );
+    assert_eq!(a.full_mul(a), U512::from(a) * U512::from(a));
+    assert_eq!(
+        U512::MAX.full_mul(U512::MAX),
+        Uint::<16>::MAX.wrapping_sub(Uint::<16>::from(U512::MAX) << 1)
+    );
+}
+
+#[test]
+fn uint_mixed_width_conversions() {
+    let a = U256!([1, 2, 3, 4]);
+    assert_eq!(Uint::<16>::from(a).0[..4], a.0);
+    assert!(Uint::<16>::from(a).0[4..].iter().all(|&w| w == 0));
+    assert_eq!(Uint::<16>::from(a).resize::<4>(), a);
+    assert_eq!(a.resize::<1>(), Uint([1]));
+    assert_eq!(Uint::<64>::MAX.resize::<2>(), Uint::<2>::MAX);
+    // Narrowing `From` only between adjacent sizes, keeping the low half
+    assert_eq!(U256::from(U512::MAX), U256::MAX);
+    assert_eq!(U512::from(Uint::<16>::from(a) << 512), U512::zero());
+    assert_eq!(a.resize::<4>(), a);
+    assert_eq!(a.resize::<6>(), Uint([1, 2, 3, 4, 0, 0]));
+    assert_eq!(a.resize::<3>(), Uint([1, 2, 3]));
+
+    assert_eq!(a.checked_resize::<2>(), None);
+    assert_eq!(
+        U256::from(u128::MAX).checked_resize::<2>(),
+        Some(Uint::<2>::MAX)
+    );
+    assert_eq!(a.checked_resize::<32>(), Some(Uint::<32>::from(a)));
+    assert_eq!(U512::from(a).checked_resize::<4>(), Some(a));
+    assert_eq!((U512::from(a) << 256).checked_resize::<4>(), None);
+}
+
+#[test]
+fn uint_mixed_width_add() {
+    let a = U512::MAX >> 64;
+    assert_eq!(
+        a.overflowing_add_mixed(U256::MAX),
+        (a + U512::from(U256::MAX), false)
+    );
+    assert_eq!(
+        U512::MAX.overflowing_add_mixed(U256::one()),
+        (U512::zero(), true)
+    );
+    assert_eq!(
+        U256::one().overflowing_add_mixed(U512::from(2u64)),
+        (U256::from(3u64), false)
+    );
+    // The high words of a wider operand count as overflow
+    assert_eq!(
+        U256::one().overflowing_add_mixed(U512::one() << 256),
+        (U256::one(), true)
+    );
+    assert_eq!(
+        U256::MAX.overflowing_add_mixed(U256::one()),
+        U256::MAX.overflowing_add(U256::one())
+    );
+}
+
+#[test]
+fn uint_split_concat() {
+    let hi = U256!([5, 6, 7, 8]);
+    let lo = U256!([1, 2, 3, 4]);
+    let wide = U256::concat(hi, lo);
+    assert_eq!(wide, Uint([1, 2, 3, 4, 5, 6, 7, 8]));
+    assert_eq!(wide.split(), (hi, lo));
+    assert_eq!(
+        Uint::<2>::from(u128::MAX).split(),
+        (Uint([MAX]), Uint([MAX]))
+    );
+    assert_eq!(
+        Uint::<1>::concat(Uint([1]), Uint([2])),
+        Uint::<2>::from(1u128 << 64 | 2)
+    );
+
+    let (lo, hi) = U512::MAX.widening_mul(U512::MAX);
+    let full = U512::MAX.full_mul(U512::MAX);
+    assert_eq!(full.split(), (hi, lo));
+    assert_eq!(
+        Uint::<32>::MAX.full_mul(Uint::<32>::one()).split(),
+        (Uint::zero(), Uint::MAX)
+    );
+}
+
+fn check_widening_mul_matches_schoolbook<const N: usize>() {
+    let mut values = vec![Uint::<N>::zero(), Uint::one(), Uint::MAX];
+    for x in common::uints::<N>(common::SEED).take(8) {
+        values.push(x);
+        // lopsided halves exercise the signs in Karatsuba
+        values.push(x >> (N * 32));
+        values.push(x << (N * 32));
+    }
+    for a in &values {
+        for b in &values {
+            assert_eq!(
+                a.widening_mul(*b),
+                common::schoolbook(*a, *b),
+                "N = {}, {:x} * {:x}",
+                N,
+                a,
+                b
+            );
+        }
+    }
+    // (2^k - 1)^2 == 2^2k - 2^(k+1) + 1
+    assert_eq!(
+        Uint::<N>::MAX.widening_mul(Uint::MAX),
+        (Uint::one(), Uint::MAX - Uint::one())
+    );
+}
+
+#[test]
+fn uint_widening_mul_matches_schoolbook() {
+    check_widening_mul_matches_schoolbook::<1>();
+    check_widening_mul_matches_schoolbook::<2>();
+    check_widening_mul_matches_schoolbook::<4>();
+    check_widening_mul_matches_schoolbook::<7>();
+    check_widening_mul_matches_schoolbook::<8>();
+    check_widening_mul_matches_schoolbook::<9>();
+    check_widening_mul_matches_schoolbook::<10>();
+    check_widening_mul_matches_schoolbook::<13>();
+    check_widening_mul_matches_schoolbook::<16>();
+    check_widening_mul_matches_schoolbook::<17>();
+    check_widening_mul_matches_schoolbook::<32>();
+    check_widening_mul_matches_schoolbook::<33>();
+    check_widening_mul_matches_schoolbook::<40>();
+    check_widening_mul_matches_schoolbook::<47>();
+    check_widening_mul_matches_schoolbook::<64>();
+}
+
+#[test]
+fn uint_widening_mul_known_answer() {
+    // 3^600 * 7^360, computed with Python integers
+    let a = "7e04ee38e4d1df8becbb6628ac423f8123e12adc5dd59b13137398541cbcf6bde33c486b91dcf2dcf9bf53817a14d473cfddc7eae21056c3c931c84384ae3cb01d4b9ba674f6617b033c01e8765d59f445076e79443d36824ad61edcee8fd983f902f6b2e210802427fdd4f9cbbe234b484e2bdefdbde1";
+    let b = "644593e374186df69d783998a28a3736789a4dcdc114bc8739cc36719bfb7be021e766e3a0f0412bd4efe183c728b7e0203e5dfc6ef2ee871b25b9831108815a05e7bcb087c5e1e2e862bbc5d4f3489fda1dc1e01252516da420a3ccf1ee1c4f8fd6413f1663523542fb722db17b3b5e6bf56bf4dadb0bc7fdf90a5813fc1";
+    let p = "315c2d2f3b07d244ef5a7a41574efd8c1db16df037c6031873cf6159b586e30a2179e359a0671a07cb3d9f7beefae5e8f6e0c9f97e5b61e18efd198b729c49a51050c1a6831ae093baff06c723bdda1b4e88a16aefa4fa2eac6f09129155375664920952a87a540328eae03f88bf26e70177c983c38c61d796d89c54cf43ebc6dfaec249b9038b0e44b3baa34943b11d537eaa4e7e62b6fb921160c98c0c7d9e4a232096ca52722fe39d9d3c16adecc07875a4857142f879c1678e2ae5a7f2922c0d57771e8602de782dceb2449d91742713c1951fa947748f771d97ad5d63aa6d85e12b33a47208c641d6e50e31d5571c2426785a1";
+    let a16 = Uint::<16>::from_str_radix(a, 16).unwrap();
+    let b16 = Uint::<16>::from_str_radix(b, 16).unwrap();
+    let p32 = Uint::<32>::from_str_radix(p, 16).unwrap();
+    let (lo, hi) = a16.widening_mul(b16);
+    assert_eq!((hi, lo), p32.split());
+    assert_eq!(
+        Uint::<32>::from_str_radix(a, 16).unwrap() * Uint::from_str_radix(b, 16).unwrap(),
+        p32
+    );
+}
+
 #[test]
 #[should_panic]
 #[allow(unused_must_use)]
@@ -641,6 +898,131 @@ fn display_uint_zero() {
     assert_eq!(format!("{}", U256::from(0)), "0");
 }
 
+#[test]
+fn uint256_from_str_radix() {
+    assert_eq!(U256::from_str_radix("101", 2).unwrap(), U256::from(5u64));
+    assert_eq!(U256::from_str_radix("0b101", 2).unwrap(), U256::from(5u64));
+    assert_eq!(
+        U256::from_str_radix("0o777", 8).unwrap(),
+        U256::from(511u64)
+    );
+    assert_eq!(
+        U256::from_str_radix("0xff", 16).unwrap(),
+        U256::from(255u64)
+    );
+    assert_eq!(U256::from_str_radix("zZ", 36).unwrap(), U256::from(1295u64));
+    assert_eq!(
+        U256::from_str_radix("1024", 10).unwrap(),
+        U256::from(1024u64)
+    );
+    assert_eq!(
+        U256::from_str_radix(&"1".repeat(256), 2).unwrap(),
+        U256::MAX
+    );
+    assert_eq!(
+        U256::from_str_radix(&"1".repeat(257), 2)
+            .unwrap_err()
+            .kind(),
+        FromStrRadixErrKind::InvalidLength
+    );
+    assert_eq!(
+        U256::from_str_radix("12", 2).unwrap_err().kind(),
+        FromStrRadixErrKind::InvalidCharacter
+    );
+    assert_eq!(
+        U256::from_str_radix("", 8).unwrap_err().kind(),
+        FromStrRadixErrKind::InvalidLength
+    );
+    assert_eq!(
+        U256::from_str_radix("1", 37).unwrap_err().kind(),
+        FromStrRadixErrKind::UnsupportedRadix
+    );
+    assert_eq!(
+        U256::from_str_radix("1", 1).unwrap_err().kind(),
+        FromStrRadixErrKind::UnsupportedRadix
+    );
+}
+
+#[test]
+fn uint_literal_macro() {
+    const P: U256 = uint!("0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47");
+    assert_eq!(
+        P,
+        U256::from_str("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47").unwrap()
+    );
+    assert_eq!(
+        uint!(4; "115792089237316195423570985008687907853269984665640564039457584007913129639935"),
+        U256::MAX
+    );
+    assert_eq!(uint!(8; "0b1_0000_0001"), U512::from(257u64));
+    assert_eq!(uint!(4; "0XFF_ff"), U256::from(0xffffu64));
+    assert_eq!(uint!(1; "0"), Uint::<1>::zero());
+    // Same limbs as the little-endian array form
+    assert_eq!(
+        uint!(4; "0x0000000000000004_0000000000000003_0000000000000002_0000000000000001"),
+        U256!([1, 2, 3, 4])
+    );
+}
+
+#[test]
+#[should_panic]
+fn uint_from_str_const_invalid_digit() {
+    let s = "0b102";
+    U256::from_str_const(s);
+}
+
+#[test]
+fn uint256_from_str_prefixed() {
+    assert_eq!(U256::from_str_prefixed("0b11").unwrap(), U256::from(3u64));
+    assert_eq!(U256::from_str_prefixed("0O17").unwrap(), U256::from(15u64));
+    assert_eq!(U256::from_str_prefixed("0x1F").unwrap(), U256::from(31u64));
+    assert_eq!(U256::from_str_prefixed("0123").unwrap(), U256::from(123u64));
+    assert!(U256::from_str_prefixed("0b12").is_err());
+}
+
+#[test]
+fn uint256_to_str_radix() {
+    let value = U256::from_dec_str(
+        "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+    )
+    .unwrap();
+    for radix in 2..=36 {
+        let s = value.to_str_radix(radix);
+        assert_eq!(U256::from_str_radix(&s, radix).unwrap(), value);
+    }
+    assert_eq!(value.to_str_radix(2), "1".repeat(256));
+    assert_eq!(U256::zero().to_str_radix(7), "0");
+    assert_eq!(U256::from(35u64).to_str_radix(36), "z");
+    // chunks below the top one are zero-padded
+    assert_eq!((U256::one() << 64).to_str_radix(10), "18446744073709551616");
+    assert_eq!(
+        (U256::one() << 128).to_str_radix(16),
+        format!("1{}", "0".repeat(32))
+    );
+}
+
+#[test]
+fn uint256_format_radix_flags() {
+    let x = U256::from(0xbeefu64);
+    assert_eq!(format!("{:X}", x), "BEEF");
+    assert_eq!(format!("{:#X}", x), "0xBEEF");
+    assert_eq!(format!("{:o}", U256::from(8u64)), "10");
+    assert_eq!(format!("{:#o}", U256::from(8u64)), "0o10");
+    assert_eq!(format!("{:b}", U256::from(5u64)), "101");
+    assert_eq!(format!("{:#b}", U256::zero()), "0b0");
+    assert_eq!(format!("{:#010b}", U256::from(5u64)), "0b00000101");
+    assert_eq!(format!("{:>8x}", x), "    beef");
+    assert_eq!(format!("{:<8x}|", x), "beef    |");
+    assert_eq!(format!("{:*^9}", U256::from(42u64)), "***42****");
+    assert_eq!(format!("{:08}", U256::from(42u64)), "00000042");
+    assert_eq!(format!("{:+}", U256::from(42u64)), "+42");
+    assert_eq!(format!("{:3}", U256::from(12345u64)), "12345");
+    assert_eq!(
+        format!("{:X}", U256::MAX),
+        format!("{:x}", U256::MAX).to_uppercase()
+    );
+}
+
 #[test]
 fn u512_multi_adds() {
     let (result, _) =
@@ -889,6 +1271,28 @@ fn u512_div() {
     assert_eq!((x, y), (q, r));
 }
 
+#[test]
+fn uint256_div_add_back() {
+    // A quotient digit guess is one too large, so Knuth's D6 step adds the
+    // divisor back: once in the middle of the dividend, and once through the
+    // extra word of the shifted dividend with a full-width divisor
+    let cases = [
+        (
+            U256!([0xc881b9c3d5dcef17, MAX - 1, 0x1af66a1cdf215539, 1 << 62]),
+            U256!([0x7be5bb98f3938c34, 1 << 62, 0, 0]),
+        ),
+        (
+            U256!([0xf90dc69e9616b8f6, 1 << 62, 1 << 63, MAX >> 1]),
+            U256!([0xef86fd798e62fa31, 1, 1 << 62, 1]),
+        ),
+    ];
+    for &(u, v) in cases.iter() {
+        let (q, r) = u.div_mod(v);
+        assert!(r < v);
+        assert_eq!(q * v + r, u);
+    }
+}
+
 #[test]
 fn big_endian() {
     let source = U256!([1, 0, 0, 0]);
@@ -1216,3 +1620,87 @@ fn trailing_zeros() {
         256
     );
 }
+
+#[test]
+fn uint256_set_bit_count_ones() {
+    let mut x = U256::zero();
+    x.set_bit(0, true);
+    x.set_bit(200, true);
+    assert_eq!(x, U256!([1, 0, 0, 1 << 8]));
+    assert!(x.bit(200));
+    assert_eq!(x.count_ones(), 2);
+    assert_eq!(x.count_zeros(), 254);
+    x.set_bit(0, false);
+    assert_eq!(x, U256!([0, 0, 0, 1 << 8]));
+    assert_eq!(U256::MAX.count_ones(), 256);
+    assert_eq!(U256::zero().count_zeros(), 256);
+}
+
+#[test]
+fn uint256_rotate() {
+    let x = U256!([0x8000_0000_0000_0001, 0, 0, 0x8000_0000_0000_0000]);
+    assert_eq!(x.rotate_left(1), U256!([3, 1, 0, 0]));
+    assert_eq!(
+        x.rotate_right(1),
+        U256!([0x4000_0000_0000_0000, 0, 0, 0xc000_0000_0000_0000])
+    );
+    assert_eq!(x.rotate_left(256), x);
+    assert_eq!(x.rotate_left(0), x);
+    assert_eq!(
+        x.rotate_left(64),
+        U256!([0x8000_0000_0000_0000, 0x8000_0000_0000_0001, 0, 0])
+    );
+    assert_eq!(x.rotate_left(77).rotate_right(77), x);
+    assert_eq!(x.rotate_left(300), x.rotate_left(44));
+}
+
+#[test]
+fn uint256_swap_reverse() {
+    let x = U256!([0x0102_0304_0506_0708, 0, 0, 0x1112_1314_1516_1718]);
+    assert_eq!(
+        x.swap_bytes(),
+        U256!([0x1817_1615_1413_1211, 0, 0, 0x0807_0605_0403_0201])
+    );
+    assert_eq!(x.swap_bytes().swap_bytes(), x);
+    assert_eq!(U256::one().reverse_bits(), U256::one() << 255);
+    assert_eq!(x.reverse_bits().reverse_bits(), x);
+}
+
+#[test]
+fn uint256_byte_arrays() {
+    let x = U256!([0x0102_0304_0506_0708, 0, 0, 0x1112_1314_1516_1718]);
+    let be = x.to_be_bytes();
+    assert_eq!(be[..8], [0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18]);
+    assert_eq!(be[31], 0x08);
+    assert_eq!(U256::from_be_bytes(be), x);
+    let le = x.to_le_bytes();
+    assert_eq!(le[0], 0x08);
+    assert_eq!(U256::from_le_bytes(le), x);
+    assert_eq!(U512::from_be_bytes(U512::MAX.to_be_bytes()), U512::MAX);
+
+    // Largest size with `From` conversions
+    let y = Uint::<64>::from(x) << 3840 | Uint::<64>::from(x);
+    let be = y.to_be_bytes();
+    assert_eq!(be[..32], x.to_be_bytes());
+    assert_eq!(be[480..], x.to_be_bytes());
+    assert_eq!(Uint::<64>::from_be_bytes(be), y);
+    assert_eq!(Uint::<64>::from_le_bytes(y.to_le_bytes()), y);
+}
+
+#[test]
+fn uint256_iter_bits() {
+    let x = U256::from(0b1101u64);
+    assert_eq!(
+        x.iter_bits().collect::<Vec<_>>(),
+        vec![true, false, true, true]
+    );
+    assert_eq!(
+        x.iter_bits_msb().collect::<Vec<_>>(),
+        vec![true, true, false, true]
+    );
+    assert_eq!(U256::zero().iter_bits().count(), 0);
+    assert_eq!(U256::MAX.iter_bits().len(), 256);
+    let y = U256::one() << 200;
+    assert_eq!(y.iter_bits().filter(|b| *b).count(), 1);
+    assert_eq!(y.iter_bits_msb().next(), Some(true));
+}
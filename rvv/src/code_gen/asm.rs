@@ -8,7 +8,7 @@ use super::{CodegenContext, OpCategory, ToTokenStream};
 use crate::ast::{Expression, TypedExpression};
 use crate::SpannedError;
 
-use rvv_assembler::{Imm, Ivi, Ivv, VConfig, VInst, VReg, Vlmul, Vtypei, XReg};
+use rvv_assembler::{Imm, Ivi, Ivv, Uimm, VConfig, VInst, VReg, Vlmul, Vtypei, XReg};
 
 impl CodegenContext {
     // Generate raw asm statements for top level expression
@@ -739,10 +739,10 @@ impl CodegenContext {
     }
 
     fn update_vconfig(&mut self, tokens: &mut TokenStream, bit_length: u16) {
-        // vsetvli x0, t0, e{256,512,1024}, m1, ta, ma
-        let v_config = VConfig::Vsetvli {
+        // vsetivli x0, 1, e{256,512,1024}, m1, ta, ma
+        let v_config = VConfig::Vsetivli {
             rd: XReg::Zero,
-            rs1: XReg::T0,
+            uimm: Uimm(1), // AVL = 1
             vtypei: Vtypei::new(bit_length, Vlmul::M1, true, true),
         };
         if self.v_config.as_ref() != Some(&v_config) {
@@ -757,10 +757,7 @@ impl CodegenContext {
             let inst_string = inst_to_string(&inst);
             let ts = quote! {
                 unsafe {
-                    asm!(
-                        "li t0, 1",  // AVL = 1
-                        #inst_string,
-                    )
+                    asm!(#inst_string)
                 }
             };
             tokens.extend(Some(ts));
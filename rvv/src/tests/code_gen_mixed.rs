@@ -98,8 +98,8 @@ fn test_u256_mixed() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn comp_u256(x: U256, y: U256, mut z: U256, w: U256) -> U256 {
-            let _ = "vsetvl zero, t0, e256, m1, ta, ma - 243462231";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0e",) }
+            let _ = "vsetivli zero, 1, e256, m1, ta, ma - 3464556631";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xce") }
             let _ = "vle256.v v1, (t0) - 302174343";
             let _tmp_t0_saved: i64;
             unsafe {
@@ -243,8 +243,8 @@ fn test_u1024() {
         #[inline(always)]
         #[no_mangle]
         fn comp_u1024(x: U1024, y: U1024) -> U1024 {
-            let _ = "vsetvl zero, t0, e1024, m1, ta, ma - 260239447";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0f",) }
+            let _ = "vsetivli zero, 1, e1024, m1, ta, ma - 3481333847";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xcf") }
             let _ = "vle1024.v v1, (t0) - 302182535";
             let _tmp_t0_saved: i64;
             unsafe {
@@ -307,8 +307,8 @@ fn test_method_call() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn comp_u1024(a: U1024, b: U1024, c: U1024, d: U1024) -> U1024 {
-            let _ = "vsetvl zero, t0, e1024, m1, ta, ma - 260239447";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0f",) }
+            let _ = "vsetivli zero, 1, e1024, m1, ta, ma - 3481333847";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xcf") }
             let _ = "vle1024.v v1, (t0) - 302182535";
             let _tmp_t0_saved: i64;
             unsafe {
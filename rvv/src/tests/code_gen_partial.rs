@@ -23,8 +23,8 @@ fn test_u256_ops_add() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn ops_add(a: U256, b: U256) -> U256 {
-            let _ = "vsetvl zero, t0, e256, m1, ta, ma - 243462231";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0e",) }
+            let _ = "vsetivli zero, 1, e256, m1, ta, ma - 3464556631";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xce") }
             let _ = "vle256.v v1, (t0) - 302174343";
             let _tmp_t0_saved: i64;
             unsafe {
@@ -74,8 +74,8 @@ fn test_u256_ops_mul() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn ops_mul(a: U256, b: U256) -> U256 {
-            let _ = "vsetvl zero, t0, e256, m1, ta, ma - 243462231";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0e",) }
+            let _ = "vsetivli zero, 1, e256, m1, ta, ma - 3464556631";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xce") }
             let _ = "vle256.v v1, (t0) - 302174343";
             let _tmp_t0_saved: i64;
             unsafe {
@@ -125,8 +125,8 @@ fn test_u256_ops_div() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn ops_div(a: U256, b: U256) -> U256 {
-            let _ = "vsetvl zero, t0, e256, m1, ta, ma - 243462231";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0e",) }
+            let _ = "vsetivli zero, 1, e256, m1, ta, ma - 3464556631";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xce") }
             let _ = "vle256.v v1, (t0) - 302174343";
             let _tmp_t0_saved: i64;
             unsafe {
@@ -180,8 +180,8 @@ fn test_u256_ops_rem() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn ops_rem(a: U256, b: U256) -> U256 {
-            let _ = "vsetvl zero, t0, e256, m1, ta, ma - 243462231";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0e",) }
+            let _ = "vsetivli zero, 1, e256, m1, ta, ma - 3464556631";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xce") }
             let _ = "vle256.v v1, (t0) - 302174343";
             let _tmp_t0_saved: i64;
             unsafe {
@@ -230,8 +230,8 @@ fn test_u256_ops_bitxor() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn ops_bitxor(a: U256, b: U256) -> U256 {
-            let _ = "vsetvl zero, t0, e256, m1, ta, ma - 243462231";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0e",) }
+            let _ = "vsetivli zero, 1, e256, m1, ta, ma - 3464556631";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xce") }
             let _ = "vle256.v v1, (t0) - 302174343";
             let _tmp_t0_saved: i64;
             unsafe {
@@ -275,8 +275,8 @@ fn test_u256_ops_shl() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn ops_shl(a: U256, b: U256) -> U256 {
-            let _ = "vsetvl zero, t0, e256, m1, ta, ma - 243462231";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0e",) }
+            let _ = "vsetivli zero, 1, e256, m1, ta, ma - 3464556631";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xce") }
             let _ = "vle256.v v1, (t0) - 302174343";
             let _tmp_t0_saved: i64;
             unsafe {
@@ -320,8 +320,8 @@ fn test_u256_ops_add_assign() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn ops_add_assign(mut a: U256, b: U256) {
-            let _ = "vsetvl zero, t0, e256, m1, ta, ma - 243462231";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0e",) }
+            let _ = "vsetivli zero, 1, e256, m1, ta, ma - 3464556631";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xce") }
             let _ = "vle256.v v1, (t0) - 302174343";
             let _tmp_t0_saved: i64;
             unsafe {
@@ -355,8 +355,8 @@ fn test_u256_ops_mul_assign() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn ops_mul_assign(mut a: U256, b: U256) {
-            let _ = "vsetvl zero, t0, e256, m1, ta, ma - 243462231";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0e",) }
+            let _ = "vsetivli zero, 1, e256, m1, ta, ma - 3464556631";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xce") }
             let _ = "vle256.v v1, (t0) - 302174343";
             let _tmp_t0_saved: i64;
             unsafe {
@@ -390,8 +390,8 @@ fn test_u256_ops_div_assign() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn ops_div_assign(mut a: U256, b: U256) {
-            let _ = "vsetvl zero, t0, e256, m1, ta, ma - 243462231";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0e",) }
+            let _ = "vsetivli zero, 1, e256, m1, ta, ma - 3464556631";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xce") }
             let _ = "vle256.v v1, (t0) - 302174343";
             let _tmp_t0_saved: i64;
             unsafe {
@@ -425,8 +425,8 @@ fn test_u256_ops_rem_assign() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn ops_rem_assign(mut a: U256, b: U256) {
-            let _ = "vsetvl zero, t0, e256, m1, ta, ma - 243462231";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0e",) }
+            let _ = "vsetivli zero, 1, e256, m1, ta, ma - 3464556631";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xce") }
             let _ = "vle256.v v1, (t0) - 302174343";
             let _tmp_t0_saved: i64;
             unsafe {
@@ -461,8 +461,8 @@ fn test_u256_ops_bitxor_assign() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn ops_bitxor_assign(mut a: U256, b: U256) {
-            let _ = "vsetvl zero, t0, e256, m1, ta, ma - 243462231";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0e",) }
+            let _ = "vsetivli zero, 1, e256, m1, ta, ma - 3464556631";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xce") }
             let _ = "vle256.v v1, (t0) - 302174343";
             let _tmp_t0_saved: i64;
             unsafe {
@@ -496,8 +496,8 @@ fn test_u256_ops_shl_assign() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn ops_shl_assign(mut a: U256, b: U256) {
-            let _ = "vsetvl zero, t0, e256, m1, ta, ma - 243462231";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0e",) }
+            let _ = "vsetivli zero, 1, e256, m1, ta, ma - 3464556631";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xce") }
             let _ = "vle256.v v1, (t0) - 302174343";
             let _tmp_t0_saved: i64;
             unsafe {
@@ -532,8 +532,8 @@ fn test_u256_ops_eq() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn ops_eq(a: U256, b: U256) -> bool {
-            let _ = "vsetvl zero, t0, e256, m1, ta, ma - 243462231";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0e",) }
+            let _ = "vsetivli zero, 1, e256, m1, ta, ma - 3464556631";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xce") }
             let _ = "vle256.v v1, (t0) - 302174343";
             let _tmp_t0_saved: i64;
             unsafe {
@@ -577,8 +577,8 @@ fn test_u256_ops_lt() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn ops_lt(a: U256, b: U256) -> bool {
-            let _ = "vsetvl zero, t0, e256, m1, ta, ma - 243462231";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0e",) }
+            let _ = "vsetivli zero, 1, e256, m1, ta, ma - 3464556631";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xce") }
             let _ = "vle256.v v1, (t0) - 302174343";
             let _tmp_t0_saved: i64;
             unsafe {
@@ -621,8 +621,8 @@ fn test_u256_ops_le() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn ops_le(a: U256, b: U256) -> bool {
-            let _ = "vsetvl zero, t0, e256, m1, ta, ma - 243462231";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0e",) }
+            let _ = "vsetivli zero, 1, e256, m1, ta, ma - 3464556631";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xce") }
             let _ = "vle256.v v1, (t0) - 302174343";
             let _tmp_t0_saved: i64;
             unsafe {
@@ -665,8 +665,8 @@ fn test_u256_ops_ne() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn ops_ne(a: U256, b: U256) -> bool {
-            let _ = "vsetvl zero, t0, e256, m1, ta, ma - 243462231";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0e",) }
+            let _ = "vsetivli zero, 1, e256, m1, ta, ma - 3464556631";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xce") }
             let _ = "vle256.v v1, (t0) - 302174343";
             let _tmp_t0_saved: i64;
             unsafe {
@@ -709,8 +709,8 @@ fn test_u256_ops_ge() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn ops_ge(a: U256, b: U256) -> bool {
-            let _ = "vsetvl zero, t0, e256, m1, ta, ma - 243462231";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0e",) }
+            let _ = "vsetivli zero, 1, e256, m1, ta, ma - 3464556631";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xce") }
             let _ = "vle256.v v1, (t0) - 302174343";
             let _tmp_t0_saved: i64;
             unsafe {
@@ -753,8 +753,8 @@ fn test_u256_ops_gt() {
     #[cfg(not(feature = "simulator"))]
     let expected_output = quote! {
         fn ops_gt(a: U256, b: U256) -> bool {
-            let _ = "vsetvl zero, t0, e256, m1, ta, ma - 243462231";
-            unsafe { asm!("li t0, 1", ".byte 0x57, 0xf0, 0x82, 0x0e",) }
+            let _ = "vsetivli zero, 1, e256, m1, ta, ma - 3464556631";
+            unsafe { asm!(".byte 0x57, 0xf0, 0x80, 0xce") }
             let _ = "vle256.v v1, (t0) - 302174343";
             let _tmp_t0_saved: i64;
             unsafe {